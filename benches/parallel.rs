@@ -0,0 +1,25 @@
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use dou_dizhu::*;
+use rayon::prelude::*;
+
+fn parallel(c: &mut Criterion) {
+    for kind in [Chain, AirplaneWithSolos, AirplaneWithPairs, FourWithDualSolo] {
+        c.bench_function(&format!("plays/full_deck/{kind:?}"), |b| {
+            b.iter(|| black_box(Hand::FULL_DECK).plays(kind).count())
+        });
+        c.bench_function(&format!("par_plays/full_deck/{kind:?}"), |b| {
+            b.iter(|| black_box(Hand::FULL_DECK).par_plays(kind).count())
+        });
+    }
+
+    c.bench_function("all_plays/full_deck", |b| {
+        b.iter(|| black_box(Hand::FULL_DECK).all_plays().count())
+    });
+    c.bench_function("par_all_plays/full_deck", |b| {
+        b.iter(|| black_box(Hand::FULL_DECK).par_all_plays().count())
+    });
+}
+
+criterion_group!(benches, parallel);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! Benchmarks of the play search.
+//! 
+//! The `plays/*` and `typed_plays/*` cases only use APIs that predate the
+//! allocation-free search, so they can be copied onto earlier revisions to
+//! compare against the search built on intermediate vectors.
+
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+
+fn search(c: &mut Criterion) {
+    let landlord = hand!(const {
+        Three: 3, Four: 3, Five: 3, Seven, Nine: 2, Jack, Queen, King, Ace: 2, Two, BlackJoker, RedJoker,
+    });
+
+    for kind in [AirplaneWithSolos, AirplaneWithPairs, FourWithDualSolo] {
+        c.bench_function(&format!("plays/full_deck/{kind:?}"), |b| {
+            b.iter(|| SearchExt::plays(black_box(Hand::FULL_DECK), PlaySpec::standard(kind)).count())
+        });
+        c.bench_function(&format!("typed_plays/full_deck/{kind:?}"), |b| {
+            b.iter(|| black_box(Hand::FULL_DECK).typed_plays(PlaySpec::standard(kind), kind).count())
+        });
+    }
+
+    c.bench_function("all_plays/landlord", |b| {
+        b.iter(|| black_box(landlord).all_plays().count())
+    });
+}
+
+criterion_group!(benches, search);
+criterion_main!(benches);
@@ -60,3 +60,31 @@ pub const fn check_partial_specs<const N: usize>(specs: [PartialSpec; N]) {
         }
     }
 }
+
+pub struct CardSpec {
+    pub rank: Rank,
+    pub more_than_four_error: &'static str,
+}
+
+pub const fn from_cards<const N: usize>(cards: [CardSpec; N]) -> Hand {
+    let mut counts = [0u8; 15];
+    {
+        let mut i = 0;
+        while i < N {
+            let rank = cards[i].rank;
+            if counts[rank as usize] == 1 {
+                match rank {
+                    Rank::BlackJoker => panic!("more than one `BJ` is specified"),
+                    Rank::RedJoker => panic!("more than one `RJ` is specified"),
+                    _ => (),
+                }
+            }
+            if counts[rank as usize] == 4 {
+                panic!("{}", cards[i].more_than_four_error);
+            }
+            counts[rank as usize] += 1;
+            i += 1;
+        }
+    }
+    Hand(counts)
+}
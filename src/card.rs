@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+use crate::{Hand, HandError, Rank};
+
+/// The suit of a non-joker card.
+/// 
+/// Suits play no role in the rules; they only tell apart the four cards of a rank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Clubs,
+    Diamonds,
+}
+
+impl Suit {
+    /// All suits, in the order in which [`Hand::deal_cards`] assigns them.
+    pub const ALL: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+
+    /// Returns the symbol of this suit, e.g. `'♠'` for spades.
+    pub const fn symbol(self) -> char {
+        match self {
+            Suit::Spades => '♠',
+            Suit::Hearts => '♥',
+            Suit::Clubs => '♣',
+            Suit::Diamonds => '♦',
+        }
+    }
+}
+
+/// A single card of a deck.
+/// 
+/// Every card but the jokers has a suit. [`Hand`] only keeps track of the number
+/// of cards of each rank; `Card` is meant for presenting the cards of a hand.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// let card = Card::new(Rank::Ace, Suit::Spades).unwrap();
+/// assert_eq!(card.rank(), Rank::Ace);
+/// assert_eq!(card.suit(), Some(Suit::Spades));
+/// 
+/// assert_eq!(Card::new(Rank::RedJoker, Suit::Hearts), None);
+/// assert_eq!(Card::joker(Rank::RedJoker).unwrap().suit(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Card {
+    rank: Rank,
+    suit: Option<Suit>,
+}
+
+impl Card {
+    /// Returns the card of the given non-joker rank and suit, or `None` if `rank`
+    /// is a joker.
+    pub const fn new(rank: Rank, suit: Suit) -> Option<Card> {
+        match rank {
+            Rank::BlackJoker | Rank::RedJoker => None,
+            _ => Some(Card { rank, suit: Some(suit) }),
+        }
+    }
+
+    /// Returns the joker of the given rank, or `None` if `rank` is not a joker.
+    pub const fn joker(rank: Rank) -> Option<Card> {
+        match rank {
+            Rank::BlackJoker | Rank::RedJoker => Some(Card { rank, suit: None }),
+            _ => None,
+        }
+    }
+
+    /// Returns the rank of this card.
+    pub const fn rank(self) -> Rank {
+        self.rank
+    }
+
+    /// Returns the suit of this card, or `None` for a joker.
+    pub const fn suit(self) -> Option<Suit> {
+        self.suit
+    }
+}
+
+impl Hand {
+    /// Creates a hand holding the given cards.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HandError::DuplicateCard`] if some card is given twice.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let cards = [
+    ///     Card::new(Rank::Three, Suit::Spades).unwrap(),
+    ///     Card::new(Rank::Three, Suit::Hearts).unwrap(),
+    ///     Card::joker(Rank::BlackJoker).unwrap(),
+    /// ];
+    /// assert_eq!(Hand::from_cards(&cards), Ok(hand!(const { Three: 2, BlackJoker })));
+    /// 
+    /// let twice = [cards[0], cards[2], cards[0]];
+    /// assert_eq!(Hand::from_cards(&twice), Err(HandError::DuplicateCard { card: cards[0] }));
+    /// ```
+    pub fn from_cards(cards: &[Card]) -> Result<Hand, HandError> {
+        let mut counts = [0u8; 15];
+        for (i, card) in cards.iter().enumerate() {
+            if cards[..i].contains(card) {
+                return Err(HandError::DuplicateCard { card: *card });
+            }
+            counts[card.rank as usize] += 1;
+        }
+        Ok(Hand(counts))
+    }
+
+    /// Returns the cards of this hand in ascending order of rank.
+    /// 
+    /// Since a hand does not keep track of suits, the cards of each rank are given
+    /// the suits in the order of [`Suit::ALL`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 2, RedJoker });
+    /// let cards = hand.deal_cards();
+    /// assert_eq!(cards, vec![
+    ///     Card::new(Rank::Three, Suit::Spades).unwrap(),
+    ///     Card::new(Rank::Three, Suit::Hearts).unwrap(),
+    ///     Card::joker(Rank::RedJoker).unwrap(),
+    /// ]);
+    /// assert_eq!(Hand::from_cards(&cards), Ok(hand));
+    /// assert_eq!(Hand::FULL_DECK.deal_cards().len(), 54);
+    /// ```
+    pub fn deal_cards(&self) -> Vec<Card> {
+        let mut cards = Vec::with_capacity(self.len());
+        for rank in Rank::iter() {
+            for i in 0..self[rank] as usize {
+                let suit = (rank.max_count() == 4).then(|| Suit::ALL[i]);
+                cards.push(Card { rank, suit });
+            }
+        }
+        cards
+    }
+}
@@ -3,12 +3,13 @@
 //! This module provides tools for breaking down a [`Hand`]
 //! into its raw structural components.
 
-use std::mem;
+use alloc::vec::Vec;
+use core::{fmt, mem};
 use crate::{core::Guard, Hand, Play, PlayKind, Rank};
 
 /// A group of ranks that all appear with the same multiplicity (1, 2, 3, or 4)
 /// and whether they form a consecutive run.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Group {
     pub ranks: Vec<Rank>,
     pub consecutive: bool,
@@ -28,7 +29,7 @@ pub struct Group {
 /// assert_eq!(comp.solos.ranks, vec![Rank::Three]);
 /// assert!(comp.solos.consecutive);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Composition {
     pub solos: Group,
     pub pairs: Group,
@@ -77,6 +78,118 @@ impl Guard<Composition> {
         None
     }
 
+    /// Returns every standard play matching this composition, in the order of
+    /// [`PlayKind`].
+    /// 
+    /// Under the rules implemented by this crate, the patterns of the standard
+    /// play kinds are mutually exclusive, so the result holds at most one play,
+    /// which is the one returned by [`guess_play`](Self::guess_play). For example,
+    /// four cards of a rank form a bomb, while four with dual solos needs two more
+    /// cards.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 4 }).composition();
+    /// let plays = comp.all_matching_plays();
+    /// assert_eq!(plays.len(), 1);
+    /// assert!(matches!(*plays[0], Play::Bomb(Rank::Three)));
+    /// 
+    /// assert!(hand!(const { Three, Four }).composition().all_matching_plays().is_empty());
+    /// ```
+    #[doc(alias = "guess_all_plays")]
+    pub fn all_matching_plays(&self) -> Vec<Guard<Play>> {
+        PlayKind::all()
+            .iter()
+            .filter_map(|&kind| self.to_play(kind))
+            .collect()
+    }
+
+    /// Returns the kinds of the standard plays matching this composition, in the
+    /// order of [`PlayKind`].
+    /// 
+    /// These are the kinds of the plays returned by
+    /// [`all_matching_plays`](Self::all_matching_plays).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 3, Four: 3, Five, Six }).composition();
+    /// assert_eq!(comp.matching_play_kinds(), vec![AirplaneWithSolos]);
+    /// assert!(hand!(const { Three, Five }).composition().matching_play_kinds().is_empty());
+    /// ```
+    pub fn matching_play_kinds(&self) -> Vec<PlayKind> {
+        PlayKind::all()
+            .iter()
+            .copied()
+            .filter(|&kind| self.to_play(kind).is_some())
+            .collect()
+    }
+
+    /// Returns `true` if the hand of this composition holds four cards of some rank.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// assert!(hand!(const { Three: 4, Five }).composition().has_bomb());
+    /// assert!(!hand!(const { Three: 3, BlackJoker, RedJoker }).composition().has_bomb());
+    /// ```
+    pub fn has_bomb(&self) -> bool {
+        !self.fours.ranks.is_empty()
+    }
+
+    /// Returns `true` if the hand of this composition holds both jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// assert!(hand!(const { Three: 3, BlackJoker, RedJoker }).composition().has_rocket());
+    /// assert!(!hand!(const { Three: 4, RedJoker }).composition().has_rocket());
+    /// ```
+    pub fn has_rocket(&self) -> bool {
+        contains_rocket(&self.solos.ranks)
+    }
+
+    /// Returns the number of cards in the hand of this composition.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let hand = hand!(const { Three: 4, Five: 2, Nine: 3, RedJoker });
+    /// assert_eq!(hand.composition().card_count(), hand.len());
+    /// ```
+    pub fn card_count(&self) -> usize {
+        self.groups().iter().zip(1..).map(|(group, size)| group.len() * size).sum()
+    }
+
+    /// Returns the groups of this composition, i.e. the solos, pairs, trios and
+    /// fours in that order, so that the group at index `i` holds the ranks
+    /// appearing `i + 1` times.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 2, Five: 2, Nine, Jack: 4 }).composition();
+    /// let lens = comp.groups().map(|group| group.len());
+    /// assert_eq!(lens, [1, 2, 0, 1]);
+    /// assert_eq!(comp.groups()[1], &comp.pairs);
+    /// ```
+    pub fn groups(&self) -> [&Group; 4] {
+        [&self.solos, &self.pairs, &self.trios, &self.fours]
+    }
+
     /// Attempt to convert this composition into the requested play kind,
     /// returning `None` if the structure doesn't match.
     /// 
@@ -207,14 +320,40 @@ impl Guard<Composition> {
     }
 
     /// Return an AirplaneWithSolos if and only if there are consecutive trios with the same number of single kickers (not a rocket).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 3, Four: 3, Five: 3, Six, BlackJoker, RedJoker }).composition();
+    /// assert!(comp.to_airplane_with_solos().is_none());
+    /// 
+    /// let comp = hand!(const { Three: 3, Four: 3, Five: 3, Six, Two, RedJoker }).composition();
+    /// assert!(comp.to_airplane_with_solos().is_some());
+    /// 
+    /// // The rocket is rejected wherever the jokers end up among the kickers.
+    /// let comp = hand!(const { Six: 3, Seven: 3, Eight: 3, Nine: 3, Three, Ace, BlackJoker, RedJoker }).composition();
+    /// assert!(comp.to_airplane_with_solos().is_none());
+    /// let comp = hand!(const { Six: 3, Seven: 3, BlackJoker, RedJoker }).composition();
+    /// assert!(comp.to_airplane_with_solos().is_none());
+    /// 
+    /// // A kicker of a trio rank makes a four of a kind, which is not an airplane.
+    /// assert!(hand!(const { Three: 4, Four: 3 }).composition().to_airplane_with_solos().is_none());
+    /// assert!(hand!(const { Three: 4, Four: 3 }).to_play().is_none());
+    /// assert!(hand!(const { Three: 4, Four: 3, Five: 3, Six }).to_play().is_none());
+    /// 
+    /// // Nor does the search produce such plays.
+    /// assert!(Hand::FULL_DECK.plays(AirplaneWithSolos).all(|play| {
+    ///     let hand = play.to_hand();
+    ///     hand.count_by_multiplicity()[4] == 0
+    ///         && (hand[Rank::BlackJoker] == 0 || hand[Rank::RedJoker] == 0)
+    /// }));
+    /// ```
     pub fn to_airplane_with_solos(&self) -> Option<Guard<Play>> {
         if self.solos.ranks.len() == self.trios.ranks.len()
             && self.solos.ranks.len() >= 2
-            // make sure rocket not in kicker cards
-            && !(
-                self.solos.ranks[self.solos.ranks.len() - 1] == Rank::RedJoker
-                && self.solos.ranks[self.solos.ranks.len() - 2] == Rank::BlackJoker
-            )
+            && !contains_rocket(&self.solos.ranks)
             && self.pairs.ranks.is_empty()
             && self.trios.consecutive
             && self.fours.ranks.is_empty()
@@ -275,9 +414,19 @@ impl Guard<Composition> {
     }
 
     /// Return FourWithDualSolo if and only if there is exactly one four-of-a-kind and two non-rocket singles.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// assert!(hand!(const { Three: 4, Two, BlackJoker }).composition().to_four_with_dual_solo().is_some());
+    /// assert!(hand!(const { Three: 4, Two, RedJoker }).composition().to_four_with_dual_solo().is_some());
+    /// assert!(hand!(const { Three: 4, BlackJoker, RedJoker }).composition().to_four_with_dual_solo().is_none());
+    /// ```
     pub fn to_four_with_dual_solo(&self) -> Option<Guard<Play>> {
         if self.solos.ranks.len() == 2
-            && self.solos.ranks[0] != Rank::BlackJoker // make sure rocket != kicker cards
+            && !contains_rocket(&self.solos.ranks)
             && self.pairs.ranks.is_empty()
             && self.trios.ranks.is_empty()
             && self.fours.ranks.len() == 1
@@ -327,6 +476,187 @@ impl Guard<Composition> {
             None
         }
     }
+
+    /// Returns the composition of the hand remaining after `play` is removed from
+    /// the hand of this composition, or `None` if the hand does not hold `play`.
+    /// 
+    /// This is a convenience for when only the composition is at hand: the card
+    /// counts are read back from the groups and the composition of the remaining
+    /// hand is computed anew, so it is no faster than calling
+    /// [`composition`](CompositionExt::composition) on the remaining hand.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let hand = hand!(const { Three: 3, Four, Five: 2, Two: 4, RedJoker });
+    /// let comp = hand.composition();
+    /// let trio = play!(const { Three: 3, Four }).unwrap();
+    /// let rest = comp.remaining_after(&trio).unwrap();
+    /// assert_eq!(rest.pairs.ranks, vec![Rank::Five]);
+    /// assert!(rest.solos.ranks == vec![Rank::RedJoker] && !rest.solos.consecutive);
+    /// assert!(comp.remaining_after(&play!(const { Four: 2 }).unwrap()).is_none());
+    /// 
+    /// // Hands where every rank but the jokers appears with each multiplicity in turn.
+    /// for shift in 0..5 {
+    ///     let counts = std::array::from_fn(|i| (((i + shift) % 5) as u8).min(if i < 13 { 4 } else { 1 }));
+    ///     let hand = Hand::try_from(counts).unwrap();
+    ///     let comp = hand.composition();
+    ///     for play in hand.all_plays() {
+    ///         let expected = (hand - &play).unwrap().composition().into_inner();
+    ///         assert_eq!(comp.remaining_after(&play).unwrap().into_inner(), expected);
+    ///     }
+    /// }
+    /// ```
+    pub fn remaining_after(&self, play: &Guard<Play>) -> Option<Guard<Composition>> {
+        let mut counts = [0u8; 15];
+        for (count, group) in (1..).zip(self.groups()) {
+            for rank in group.iter() {
+                counts[rank as usize] = count;
+            }
+        }
+        (Hand(counts) - play).map(|hand| hand.composition())
+    }
+}
+
+impl Group {
+    /// Returns the number of ranks in this group.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 2, Five: 2, Nine }).composition();
+    /// assert_eq!(comp.pairs.len(), 2);
+    /// assert_eq!(comp.trios.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.ranks.len()
+    }
+
+    /// Returns `true` if this group holds no ranks.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 2, Nine }).composition();
+    /// assert!(!comp.solos.is_empty());
+    /// assert!(comp.fours.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.ranks.is_empty()
+    }
+
+    /// Returns `true` if `rank` is in this group.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three: 2, Nine }).composition();
+    /// assert!(comp.pairs.contains(Rank::Three));
+    /// assert!(!comp.solos.contains(Rank::Three));
+    /// ```
+    pub fn contains(&self, rank: Rank) -> bool {
+        self.ranks.binary_search(&rank).is_ok()
+    }
+
+    /// Returns the highest rank in this group, or `None` if it is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three, Nine, RedJoker }).composition();
+    /// assert_eq!(comp.solos.max_rank(), Some(Rank::RedJoker));
+    /// assert_eq!(comp.pairs.max_rank(), None);
+    /// ```
+    pub fn max_rank(&self) -> Option<Rank> {
+        self.ranks.last().copied()
+    }
+
+    /// Returns the lowest rank in this group, or `None` if it is empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three, Nine, RedJoker }).composition();
+    /// assert_eq!(comp.solos.min_rank(), Some(Rank::Three));
+    /// assert_eq!(comp.pairs.min_rank(), None);
+    /// ```
+    pub fn min_rank(&self) -> Option<Rank> {
+        self.ranks.first().copied()
+    }
+
+    /// Returns an iterator over the ranks in this group in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Nine: 3, Three: 3, Four }).composition();
+    /// assert!(comp.trios.iter().eq([Rank::Three, Rank::Nine]));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Rank> + '_ {
+        self.ranks.iter().copied()
+    }
+}
+
+impl fmt::Display for Group {
+    /// Writes the [symbols](Rank::symbol) of the ranks in brackets, followed by
+    /// whether they are consecutive unless the group is empty.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, rank) in self.ranks.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", rank.symbol())?;
+        }
+        f.write_str("]")?;
+        if !self.is_empty() {
+            f.write_str(if self.consecutive { "(consecutive)" } else { "(non-consecutive)" })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Composition {
+    /// Writes a compact description of the four groups for debugging.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// let comp = hand!(const { Three, Four, Five: 3, Seven: 3, BlackJoker }).composition();
+    /// assert_eq!(
+    ///     comp.to_string(),
+    ///     "solos:[3,4,B](non-consecutive) pairs:[] trios:[5,7](non-consecutive) fours:[]",
+    /// );
+    /// assert_eq!(
+    ///     hand!(const { Three, Four }).composition().to_string(),
+    ///     "solos:[3,4](consecutive) pairs:[] trios:[] fours:[]",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "solos:{} pairs:{} trios:{} fours:{}", self.solos, self.pairs, self.trios, self.fours)
+    }
+}
+
+/// Returns `true` if both jokers are among `ranks`, which must therefore not be used
+/// together as solo kickers.
+fn contains_rocket(ranks: &[Rank]) -> bool {
+    ranks.contains(&Rank::BlackJoker) && ranks.contains(&Rank::RedJoker)
 }
 
 /// Extension trait for converting a type into a [`Composition`].
@@ -334,7 +664,7 @@ impl Guard<Composition> {
 /// This trait is sealed and cannot be implemented for types outside of `dou_dizhu`.
 pub trait CompositionExt: private::Sealed {
     /// Compute the structural [`Composition`] of this hand.
-    fn composition(self) -> Guard<Composition>;
+    fn composition(&self) -> Guard<Composition>;
 }
 
 mod private {
@@ -343,8 +673,8 @@ mod private {
 }
 
 impl CompositionExt for Hand {
-    fn composition(self) -> Guard<Composition> {
-        let Hand(counts) = self;
+    fn composition(&self) -> Guard<Composition> {
+        let Hand(counts) = *self;
         let mut comp = Composition {
             solos: Group { ranks: Vec::new(), consecutive: true },
             pairs: Group { ranks: Vec::new(), consecutive: true },
@@ -361,7 +691,7 @@ impl CompositionExt for Hand {
                             $group.consecutive = false;
                         }
                     }
-                    $group.ranks.push(unsafe { mem::transmute($index) });
+                    $group.ranks.push(unsafe { mem::transmute::<u8, Rank>($index) });
                 }
             };
         }
@@ -3,7 +3,7 @@
 //! This module defines [`Guard`], a generic wrapper that marks values as
 //! having passed validation.
 
-use std::ops::Deref;
+use core::ops::Deref;
 
 /// Invariant–preserving wrapper.
 /// 
@@ -72,6 +72,67 @@ impl<T> Guard<T> {
     pub unsafe fn new_unchecked(value: T) -> Self {
         Self(value)
     }
+
+    /// Transforms the guarded value with `f`, guarding the result without
+    /// performing any validation.
+    /// 
+    /// This is a shorthand for unwrapping the value and passing the result of `f`
+    /// to [`new_unchecked`](Guard::new_unchecked), for deriving a guarded value
+    /// from another one.
+    /// 
+    /// # Safety
+    /// 
+    /// The value returned by `f` must uphold the same contract as a value passed
+    /// to [`new_unchecked`](Guard::new_unchecked).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let bomb = play!(const { Nine: 4 }).unwrap();
+    /// // A solo of any rank is valid.
+    /// let solo = unsafe {
+    ///     bomb.map(|play| match play {
+    ///         Play::Bomb(rank) => Play::Solo(rank),
+    ///         _ => unreachable!(),
+    ///     })
+    /// };
+    /// assert_eq!(solo, play!(const { Nine }).unwrap());
+    /// ```
+    pub unsafe fn map<U>(self, f: impl FnOnce(T) -> U) -> Guard<U> {
+        Guard(f(self.0))
+    }
+
+    /// Transforms the guarded value with `f`, guarding the result, if any,
+    /// without performing any validation.
+    /// 
+    /// Returns `None` if `f` does.
+    /// 
+    /// # Safety
+    /// 
+    /// The value returned by `f`, if any, must uphold the same contract as a value
+    /// passed to [`new_unchecked`](Guard::new_unchecked).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::CompositionExt};
+    /// 
+    /// // A hand holding one card is a solo.
+    /// let to_solo = |comp: core::Composition| match comp.solos.ranks[..] {
+    ///     [rank] if comp.pairs.is_empty() && comp.trios.is_empty() && comp.fours.is_empty() => {
+    ///         Some(Play::Solo(rank))
+    ///     }
+    ///     _ => None,
+    /// };
+    /// let solo = unsafe { hand!(const { Jack }).composition().try_map(to_solo) };
+    /// assert_eq!(solo, play!(const { Jack }));
+    /// assert!(unsafe { hand!(const { Jack: 2 }).composition().try_map(to_solo) }.is_none());
+    /// ```
+    pub unsafe fn try_map<U>(self, f: impl FnOnce(T) -> Option<U>) -> Option<Guard<U>> {
+        f(self.0).map(Guard)
+    }
 }
 
 // NOTE: We intentionally do NOT implement `DerefMut`.
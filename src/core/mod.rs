@@ -12,4 +12,4 @@ pub mod search;
 pub use composition::{Composition, CompositionExt, Group};
 pub use guard::Guard;
 pub use ops::{UncheckedAddExt, UncheckedSubExt};
-pub use search::{PlaySpec, SearchExt};
+pub use search::{PlaySpec, PlaySpecError, SearchExt};
@@ -1,6 +1,6 @@
 //! Arithmetic extension traits for [`Hand`] and [`Guard<Play>`].
 
-use std::ops::{Add, Sub};
+use core::{iter::Sum, ops::{Add, Sub}};
 use crate::{core::Guard, Hand, Play};
 
 /// Unchecked addition helpers for sealed operand combinations.
@@ -25,6 +25,8 @@ where
 
     /// Performs the unchecked addition operation.
     /// 
+    /// # Safety
+    /// 
     /// See [`UncheckedAddExt`] for the safety contract.
     unsafe fn unchecked_add(self, rhs: Rhs) -> Self::Output;
 }
@@ -51,6 +53,8 @@ where
 
     /// Performs the unchecked subtraction operation.
     /// 
+    /// # Safety
+    /// 
     /// See [`UncheckedSubExt`] for the safety contract.
     unsafe fn unchecked_sub(self, rhs: Rhs) -> Self::Output;
 }
@@ -212,3 +216,42 @@ impl Sub<Option<Hand>> for &Guard<Play> {
         rhs.and_then(|y| self - y)
     }
 }
+
+/// Sums hands, returning `None` as soon as a partial sum holds more cards of some
+/// rank than a complete deck does.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// let hands = [hand!(const { Three: 2 }), hand!(const { Three, Four }), Hand::EMPTY];
+/// assert_eq!(hands.into_iter().sum::<Option<Hand>>(), Some(hand!(const { Three: 3, Four })));
+/// assert_eq!([Hand::FULL_DECK, hand!(const { Four })].into_iter().sum::<Option<Hand>>(), None);
+/// ```
+impl Sum<Hand> for Option<Hand> {
+    fn sum<I: Iterator<Item = Hand>>(mut iter: I) -> Self {
+        iter.try_fold(Hand::EMPTY, |acc, x| acc + x)
+    }
+}
+
+/// Sums the cards of plays, returning `None` as soon as a partial sum holds more
+/// cards of some rank than a complete deck does.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// let mut plays = Hand::FULL_DECK.plays(Bomb).collect::<Vec<_>>();
+/// plays.push(play!(const { BlackJoker, RedJoker }).unwrap());
+/// assert_eq!(plays.iter().sum::<Option<Hand>>(), Some(Hand::FULL_DECK));
+/// 
+/// plays.push(play!(const { Three }).unwrap());
+/// assert_eq!(plays.iter().sum::<Option<Hand>>(), None);
+/// ```
+impl<'a> Sum<&'a Guard<Play>> for Option<Hand> {
+    fn sum<I: Iterator<Item = &'a Guard<Play>>>(mut iter: I) -> Self {
+        iter.try_fold(Hand::EMPTY, |acc, x| acc + x)
+    }
+}
@@ -3,9 +3,9 @@
 //! This module provides functionality for enumerating possible plays
 //! within a [`Hand`]. These plays are not necessarily standard ones.
 
-use std::{mem, ops::{Bound, RangeBounds, RangeInclusive}};
-use itertools::Itertools;
-use crate::{Hand, PlayKind, Rank};
+use core::{error::Error, fmt, iter, mem, ops::{Bound, RangeBounds, RangeInclusive}};
+use alloc::vec::Vec;
+use crate::{core::Guard, Hand, Play, PlayKind, Rank};
 
 /// Specification for searching for plays in a hand.
 /// Can be used to search for even non-standard plays.
@@ -45,9 +45,100 @@ where
     /// The closure takes the number of primal elements (`u8`) and returns
     /// the number of kicker elements (`u8`).
     pub kicker_count: F,
+
+    /// Lowest rank allowed for the first primal element, or `None` for no restriction.
+    /// 
+    /// For chain-like plays, this is a lower bound on the lowest rank of the chain.
+    pub primal_min: Option<Rank>,
+
+    /// Whether Twos and jokers may be primal elements when there are several of
+    /// them, e.g. for house rules allowing pairs chains through Two.
+    /// 
+    /// This is `false` for all standard plays, so that chain-like plays end at
+    /// Ace at the highest. Single primal elements may be of any rank regardless.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+    /// 
+    /// let hand = hand!(const { Queen: 2, King: 2, Ace: 2, Two: 2, BlackJoker, RedJoker });
+    /// let through_two = |allow| PlaySpec {
+    ///     primal_count: 3..=3,
+    ///     allow_unchainable_primal: allow,
+    ///     ..PlaySpec::standard(PairsChain)
+    /// };
+    /// 
+    /// let runs = SearchExt::plays(hand, through_two(false)).collect::<Vec<_>>();
+    /// assert_eq!(runs, vec![hand!(const { Queen: 2, King: 2, Ace: 2 })]);
+    /// 
+    /// let runs = SearchExt::plays(hand, through_two(true)).collect::<Vec<_>>();
+    /// assert_eq!(runs, vec![
+    ///     hand!(const { Queen: 2, King: 2, Ace: 2 }),
+    ///     hand!(const { King: 2, Ace: 2, Two: 2 }),
+    /// ]);
+    /// 
+    /// // Standard chains never reach Two.
+    /// assert!(SearchExt::plays(Hand::FULL_DECK, PlaySpec::standard(Chain)).all(|x| x[Rank::Two] == 0));
+    /// 
+    /// // Nor do typed plays, which are always standard.
+    /// assert_eq!(hand.typed_plays(through_two(true), PairsChain).count(), 1);
+    /// ```
+    pub allow_unchainable_primal: bool,
+
+    /// Whether each rank, indexed by `Rank as usize`, may not be a primal element.
+    /// 
+    /// Primal windows containing any of these ranks are skipped during the
+    /// search. Kickers are not affected.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+    /// 
+    /// // Chains that do not start at Three.
+    /// let spec = PlaySpec::standard(Chain).with_excluded_ranks(&[Rank::Three]);
+    /// let hand = hand!(const { Three, Four, Five, Six, Seven, Eight });
+    /// let chains = SearchExt::plays(hand, spec).collect::<Vec<_>>();
+    /// assert_eq!(chains, vec![hand!(const { Four, Five, Six, Seven, Eight })]);
+    /// 
+    /// // Trios with a solo whose trio is not of Kings; a King may still be the kicker.
+    /// let spec = PlaySpec::standard(TrioWithSolo).with_excluded_ranks(&[Rank::King]);
+    /// let hand = hand!(const { Five: 3, King: 3 });
+    /// let trios = SearchExt::plays(hand, spec).collect::<Vec<_>>();
+    /// assert_eq!(trios, vec![hand!(const { Five: 3, King })]);
+    /// ```
+    pub excluded_ranks: [bool; 15],
 }
 
 impl PlaySpec<RangeInclusive<u8>, fn(u8) -> u8> {
+    /// Returns a `PlaySpec` for solos, to be customized with the builder methods.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+    /// 
+    /// // Two consecutive pairs with a solo kicker per pair, which is not a standard play.
+    /// let spec = PlaySpec::new()
+    ///     .primal_size(2)
+    ///     .primal_count(2..=2)
+    ///     .kicker_size(1)
+    ///     .kicker_count(|n| n)
+    ///     .build()
+    ///     .unwrap();
+    /// let hand = hand!(const { Three: 2, Four: 2, Five: 2, Nine });
+    /// assert_eq!(SearchExt::plays(hand, spec).count(), 2);
+    /// 
+    /// assert_eq!(
+    ///     PlaySpec::new().kicker_size(1).build().err(),
+    ///     Some(core::search::PlaySpecError::KickerCountMismatch { primal_count: 1 }),
+    /// );
+    /// ```
+    pub const fn new() -> Self {
+        Self::standard(PlayKind::Solo)
+    }
+
     /// Returns a `PlaySpec` configured for the given standard `PlayKind`.
     /// 
     /// # Panics
@@ -55,33 +146,326 @@ impl PlaySpec<RangeInclusive<u8>, fn(u8) -> u8> {
     /// Panics for `PlayKind::Rocket`, which cannot be represented by `PlaySpec`.
     pub const fn standard(kind: PlayKind) -> Self {
         match kind {
-            PlayKind::Solo => Self { primal_size: 1, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::Chain => Self { primal_size: 1, primal_count: 5..=12, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::Pair => Self { primal_size: 2, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::PairsChain => Self { primal_size: 2, primal_count: 3..=12, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::Trio => Self { primal_size: 3, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::Airplane => Self { primal_size: 3, primal_count: 2..=12, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::TrioWithSolo => Self { primal_size: 3, primal_count: 1..=1, kicker_size: 1, kicker_count: |_| 1 },
-            PlayKind::AirplaneWithSolos => Self { primal_size: 3, primal_count: 2..=7, kicker_size: 1, kicker_count: |x| x },
-            PlayKind::TrioWithPair => Self { primal_size: 3, primal_count: 1..=1, kicker_size: 2, kicker_count: |_| 1 },
-            PlayKind::AirplaneWithPairs => Self { primal_size: 3, primal_count: 2..=7, kicker_size: 2, kicker_count: |x| x },
-            PlayKind::Bomb => Self { primal_size: 4, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0 },
-            PlayKind::FourWithDualSolo => Self { primal_size: 4, primal_count: 1..=1, kicker_size: 1, kicker_count: |_| 2 },
-            PlayKind::FourWithDualPair => Self { primal_size: 4, primal_count: 1..=1, kicker_size: 2, kicker_count: |_| 2 },
+            PlayKind::Solo => Self { primal_size: 1, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::Chain => Self { primal_size: 1, primal_count: 5..=12, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::Pair => Self { primal_size: 2, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::PairsChain => Self { primal_size: 2, primal_count: 3..=12, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::Trio => Self { primal_size: 3, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::Airplane => Self { primal_size: 3, primal_count: 2..=12, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::TrioWithSolo => Self { primal_size: 3, primal_count: 1..=1, kicker_size: 1, kicker_count: |_| 1, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::AirplaneWithSolos => Self { primal_size: 3, primal_count: 2..=7, kicker_size: 1, kicker_count: |x| x, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::TrioWithPair => Self { primal_size: 3, primal_count: 1..=1, kicker_size: 2, kicker_count: |_| 1, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::AirplaneWithPairs => Self { primal_size: 3, primal_count: 2..=6, kicker_size: 2, kicker_count: |x| x, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::Bomb => Self { primal_size: 4, primal_count: 1..=1, kicker_size: 0, kicker_count: |_| 0, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::FourWithDualSolo => Self { primal_size: 4, primal_count: 1..=1, kicker_size: 1, kicker_count: |_| 2, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
+            PlayKind::FourWithDualPair => Self { primal_size: 4, primal_count: 1..=1, kicker_size: 2, kicker_count: |_| 2, primal_min: None, allow_unchainable_primal: false, excluded_ranks: [false; 15] },
             PlayKind::Rocket => panic!("`Rocket` cannot be expressed as a `PlaySpec`"),
         }
     }
 }
 
+impl Default for PlaySpec<RangeInclusive<u8>, fn(u8) -> u8> {
+    /// Returns [`PlaySpec::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, F> PlaySpec<R, F>
+where
+    R: RangeBounds<u8>,
+    F: FnMut(u8) -> u8,
+{
+    /// Sets the [number of cards in each primal element](PlaySpec::primal_size).
+    pub fn primal_size(self, primal_size: u8) -> Self {
+        Self { primal_size, ..self }
+    }
+
+    /// Sets the [range of the number of primal elements](PlaySpec::primal_count).
+    pub fn primal_count<R2>(self, primal_count: R2) -> PlaySpec<R2, F>
+    where
+        R2: RangeBounds<u8>,
+    {
+        PlaySpec {
+            primal_size: self.primal_size,
+            primal_count,
+            kicker_size: self.kicker_size,
+            kicker_count: self.kicker_count,
+            primal_min: self.primal_min,
+            allow_unchainable_primal: self.allow_unchainable_primal,
+            excluded_ranks: self.excluded_ranks,
+        }
+    }
+
+    /// Sets the [number of cards in each kicker element](PlaySpec::kicker_size).
+    pub fn kicker_size(self, kicker_size: u8) -> Self {
+        Self { kicker_size, ..self }
+    }
+
+    /// Sets the [closure computing the number of kicker elements](PlaySpec::kicker_count).
+    pub fn kicker_count<F2>(self, kicker_count: F2) -> PlaySpec<R, F2>
+    where
+        F2: FnMut(u8) -> u8,
+    {
+        PlaySpec {
+            primal_size: self.primal_size,
+            primal_count: self.primal_count,
+            kicker_size: self.kicker_size,
+            kicker_count,
+            primal_min: self.primal_min,
+            allow_unchainable_primal: self.allow_unchainable_primal,
+            excluded_ranks: self.excluded_ranks,
+        }
+    }
+
+    /// Sets the [lowest rank allowed for the first primal element](PlaySpec::primal_min).
+    pub fn primal_min(self, primal_min: Option<Rank>) -> Self {
+        Self { primal_min, ..self }
+    }
+
+    /// Sets [whether Twos and jokers may be chained](PlaySpec::allow_unchainable_primal).
+    pub fn allow_unchainable_primal(self, allow_unchainable_primal: bool) -> Self {
+        Self { allow_unchainable_primal, ..self }
+    }
+
+    /// Sets the [ranks that may not be primal elements](PlaySpec::excluded_ranks).
+    /// 
+    /// Ranks excluded before remain excluded.
+    pub fn with_excluded_ranks(mut self, ranks: &[Rank]) -> Self {
+        for &rank in ranks {
+            self.excluded_ranks[rank as usize] = true;
+        }
+        self
+    }
+
+    /// Validates this spec, returning it if it is consistent.
+    /// 
+    /// # Errors
+    /// 
+    /// See [`validate`](PlaySpec::validate).
+    pub fn build(mut self) -> Result<Self, PlaySpecError> {
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Checks that this spec is consistent.
+    /// 
+    /// The [`kicker_count`](PlaySpec::kicker_count) closure is called for every
+    /// number of primal elements in range, hence the mutable borrow.
+    /// 
+    /// # Errors
+    /// 
+    /// - [`PlaySpecError::InvalidPrimalSize`] unless `primal_size` is in `1..=4`.
+    /// - [`PlaySpecError::InvalidKickerSize`] if `kicker_size` exceeds 4.
+    /// - [`PlaySpecError::EmptyPrimalCount`] if `primal_count` holds no number in
+    ///   `1..=12`.
+    /// - [`PlaySpecError::KickerCountMismatch`] if there are kicker elements but
+    ///   `kicker_size` is 0, or the other way around.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, search::PlaySpecError}};
+    /// 
+    /// for kind in PlayKind::all_non_bomb() {
+    ///     assert_eq!(PlaySpec::standard(*kind).validate(), Ok(()));
+    /// }
+    /// assert_eq!(
+    ///     PlaySpec::standard(Chain).primal_count(13..).validate(),
+    ///     Err(PlaySpecError::EmptyPrimalCount),
+    /// );
+    /// assert_eq!(
+    ///     PlaySpec::new().primal_size(5).validate(),
+    ///     Err(PlaySpecError::InvalidPrimalSize { size: 5 }),
+    /// );
+    /// ```
+    pub fn validate(&mut self) -> Result<(), PlaySpecError> {
+        if !(1..=4).contains(&self.primal_size) {
+            return Err(PlaySpecError::InvalidPrimalSize { size: self.primal_size });
+        }
+        if self.kicker_size > 4 {
+            return Err(PlaySpecError::InvalidKickerSize { size: self.kicker_size });
+        }
+        let (min, max) = primal_count_bounds(&self.primal_count);
+        if min > max {
+            return Err(PlaySpecError::EmptyPrimalCount);
+        }
+        for primal_count in min..=max {
+            if ((self.kicker_count)(primal_count) == 0) != (self.kicker_size == 0) {
+                return Err(PlaySpecError::KickerCountMismatch { primal_count });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned by [`PlaySpec::validate`] for an inconsistent spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaySpecError {
+    /// The primal elements do not have between 1 and 4 cards each.
+    InvalidPrimalSize { size: u8 },
+    /// The kicker elements have more than 4 cards each.
+    InvalidKickerSize { size: u8 },
+    /// No number of primal elements that a search can produce is in range.
+    EmptyPrimalCount,
+    /// For `primal_count` primal elements, there are kicker elements of no cards,
+    /// or no kicker elements although they have cards.
+    KickerCountMismatch { primal_count: u8 },
+}
+
+impl fmt::Display for PlaySpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPrimalSize { size } => write!(f, "invalid primal element size: expected 1 to 4, got {}", size),
+            Self::InvalidKickerSize { size } => write!(f, "invalid kicker element size: expected at most 4, got {}", size),
+            Self::EmptyPrimalCount => write!(f, "the range of primal element counts is empty"),
+            Self::KickerCountMismatch { primal_count } => write!(f, "kicker count and kicker size disagree for {} primal elements", primal_count),
+        }
+    }
+}
+
+impl Error for PlaySpecError {}
+
 /// Extension trait for searching for possible plays within a [`Hand`].
 /// 
 /// This trait is sealed and cannot be implemented for types outside of `dou_dizhu`.
 pub trait SearchExt: private::Sealed {
     /// Returns an iterator over all plays in this hand that match the given [`PlaySpec`].
+    /// 
+    /// Plays are yielded by ascending number of primal elements, then by ascending
+    /// lowest primal rank, then by kickers in lexicographic order of their ranks,
+    /// with kickers containing a joker last.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+    /// 
+    /// // Order-sensitive fingerprint of the yielded hands.
+    /// fn fingerprint(hands: impl Iterator<Item = Hand>) -> (usize, u64) {
+    ///     hands.fold((0, 0xcbf2_9ce4_8422_2325), |(n, hash), hand| {
+    ///         let hash = hand
+    ///             .to_array()
+    ///             .into_iter()
+    ///             .fold(hash, |h, c| (h ^ c as u64).wrapping_mul(0x100_0000_01b3));
+    ///         (n + 1, hash)
+    ///     })
+    /// }
+    /// 
+    /// let deck = Hand::FULL_DECK;
+    /// let mixed = hand!(const {
+    ///     Three: 3, Four: 3, Five: 2, Seven, Ace: 2, Two: 4, BlackJoker, RedJoker,
+    /// });
+    /// 
+    /// for (hand, kind, expected) in [
+    ///     (deck, Chain, (36, 12909904083378668793)),
+    ///     (deck, PairsChain, (55, 7464103063428744319)),
+    ///     (deck, AirplaneWithSolos, (7516, 2783333364924661321)),
+    ///     (deck, AirplaneWithPairs, (3436, 13287073311790653273)),
+    ///     (deck, FourWithDualSolo, (1170, 9177215085096205509)),
+    ///     (mixed, AirplaneWithSolos, (14, 8823600550633054911)),
+    ///     (mixed, FourWithDualSolo, (20, 5043688231741355607)),
+    ///     (mixed, TrioWithSolo, (21, 803334512036897003)),
+    /// ] {
+    ///     assert_eq!(fingerprint(SearchExt::plays(hand, PlaySpec::standard(kind))), expected);
+    /// }
+    /// 
+    /// let custom = || PlaySpec {
+    ///     primal_size: 2,
+    ///     primal_count: ..,
+    ///     kicker_size: 1,
+    ///     kicker_count: |n| n + 1,
+    ///     primal_min: None,
+    ///     allow_unchainable_primal: false,
+    ///     excluded_ranks: [false; 15],
+    /// };
+    /// assert_eq!(fingerprint(SearchExt::plays(deck, custom())), (13322, 4633249093371375805));
+    /// assert_eq!(fingerprint(SearchExt::plays(mixed, custom())), (134, 17177244613463859685));
+    /// ```
     fn plays<R, F>(self, spec: PlaySpec<R, F>) -> impl Iterator<Item = Hand>
     where
         R: RangeBounds<u8>,
         F: FnMut(u8) -> u8;
+
+    /// Returns an iterator over all plays in this hand that match the given [`PlaySpec`]
+    /// and whose lowest primal rank is higher than `min_rank`.
+    /// 
+    /// For chain-like plays, the whole chain thus lies above `min_rank`. Kickers are
+    /// not affected by the floor. Primal windows below the floor are skipped during
+    /// the search rather than filtered afterwards. Plays are yielded in the same
+    /// order as by [`plays`](SearchExt::plays).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+    /// 
+    /// let lowest_with_count = |hand: Hand, count: u8| {
+    ///     hand.to_array().iter().position(|&c| c == count).unwrap()
+    /// };
+    /// 
+    /// for (kind, primal_size) in [(Chain, 1), (PairsChain, 2), (TrioWithSolo, 3), (AirplaneWithPairs, 3)] {
+    ///     let filtered = SearchExt::plays(Hand::FULL_DECK, PlaySpec::standard(kind))
+    ///         .filter(|&hand| lowest_with_count(hand, primal_size) > Rank::Six as usize)
+    ///         .collect::<Vec<_>>();
+    ///     let pruned = Hand::FULL_DECK
+    ///         .plays_above(PlaySpec::standard(kind), Rank::Six)
+    ///         .collect::<Vec<_>>();
+    ///     assert_eq!(filtered, pruned);
+    /// }
+    /// ```
+    fn plays_above<R, F>(self, spec: PlaySpec<R, F>, min_rank: Rank) -> impl Iterator<Item = Hand>
+    where
+        R: RangeBounds<u8>,
+        F: FnMut(u8) -> u8;
+
+    /// Returns an iterator over all standard plays of the given kind in this hand
+    /// that match the given [`PlaySpec`].
+    /// 
+    /// Unlike [`plays`](SearchExt::plays), the plays are built directly from the
+    /// ranks chosen during the search, without recomputing their composition.
+    /// Matches of `spec` that do not form a standard play of `kind` are skipped,
+    /// so `spec` can be used to narrow down a search, e.g. to chains of a certain length.
+    /// In particular, [`allow_unchainable_primal`](PlaySpec::allow_unchainable_primal)
+    /// is ignored.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics for `PlayKind::Rocket`, which cannot be represented by `PlaySpec`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::{PlaySpec, SearchExt}};
+    /// 
+    /// let chains = Hand::FULL_DECK.typed_plays(
+    ///     PlaySpec { primal_count: 7..=7, ..PlaySpec::standard(Chain) },
+    ///     Chain,
+    /// );
+    /// assert_eq!(chains.count(), 6);
+    /// ```
+    #[doc(alias = "plays_as_guard")]
+    fn typed_plays<R, F>(self, spec: PlaySpec<R, F>, kind: PlayKind) -> impl Iterator<Item = Guard<Play>>
+    where
+        R: RangeBounds<u8>,
+        F: FnMut(u8) -> u8;
+
+    /// Returns an iterator over all bombs in this hand, from weakest to strongest,
+    /// followed by the rocket if this hand has one.
+    /// 
+    /// These are the plays that can beat a play of any kind, which
+    /// [`PlaySpec`] cannot express as a single search.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::SearchExt};
+    /// 
+    /// let hand = hand!(const { Three: 4, Seven: 3, Ace: 4, BlackJoker, RedJoker });
+    /// let plays = hand.all_bombs_and_rocket().map(|x| x.into_inner()).collect::<Vec<_>>();
+    /// assert_eq!(plays, vec![Play::Bomb(Rank::Three), Play::Bomb(Rank::Ace), Play::Rocket]);
+    /// ```
+    fn all_bombs_and_rocket(self) -> impl Iterator<Item = Guard<Play>>;
 }
 
 mod private {
@@ -90,105 +474,361 @@ mod private {
 }
 
 impl SearchExt for Hand {
-    fn plays<R, F>(self, mut spec: PlaySpec<R, F>) -> impl Iterator<Item = Hand>
+    fn plays<R, F>(self, spec: PlaySpec<R, F>) -> impl Iterator<Item = Hand>
+    where
+        R: RangeBounds<u8>,
+        F: FnMut(u8) -> u8,
+    {
+        let mut search = Search::new(self, spec, 0);
+        iter::from_fn(move || search.advance().then(|| search.hand()))
+    }
+
+    fn plays_above<R, F>(self, spec: PlaySpec<R, F>, min_rank: Rank) -> impl Iterator<Item = Hand>
+    where
+        R: RangeBounds<u8>,
+        F: FnMut(u8) -> u8,
+    {
+        let mut search = Search::new(self, spec, min_rank as u8 + 1);
+        iter::from_fn(move || search.advance().then(|| search.hand()))
+    }
+
+    fn typed_plays<R, F>(self, mut spec: PlaySpec<R, F>, kind: PlayKind) -> impl Iterator<Item = Guard<Play>>
     where
         R: RangeBounds<u8>,
         F: FnMut(u8) -> u8,
     {
-        let primal_count_min = match spec.primal_count.start_bound() {
-            Bound::Included(&n) => n,
-            Bound::Excluded(&n) => n + 1,
-            Bound::Unbounded => 1,
-        }
-        .max(1);
-
-        let primal_count_max = match spec.primal_count.end_bound() {
-            Bound::Included(&n) => n,
-            Bound::Excluded(&n) => n - 1,
-            Bound::Unbounded => 12,
-        }
-        .min(12);
-
-        (primal_count_min..=primal_count_max)
-            .filter_map(move |primal_count| {
-                let kicker_count = (spec.kicker_count)(primal_count);
-                if kicker_count + primal_count > 15 {
-                    None
-                } else {
-                    Some((primal_count, kicker_count))
+        spec.allow_unchainable_primal = false;
+        let standard = PlaySpec::standard(kind);
+        let matches_kind = spec.primal_size == standard.primal_size
+            && spec.kicker_size == standard.kicker_size;
+        TypedPlays { search: Search::new(self, spec, 0), kind, matches_kind }
+    }
+
+    fn all_bombs_and_rocket(self) -> impl Iterator<Item = Guard<Play>> {
+        let has_rocket = self.0[Rank::BlackJoker as usize] == 1
+            && self.0[Rank::RedJoker as usize] == 1;
+        self.typed_plays(PlaySpec::standard(PlayKind::Bomb), PlayKind::Bomb)
+            .chain(has_rocket.then_some(Guard(Play::Rocket)))
+    }
+}
+
+/// Iterator over the standard plays of the given kind among the matches of a
+/// [`Search`], which yields nothing unless `matches_kind`.
+#[derive(Debug, Clone)]
+pub(crate) struct TypedPlays<F> {
+    search: Search<F>,
+    kind: PlayKind,
+    matches_kind: bool,
+}
+
+impl<F> Iterator for TypedPlays<F>
+where
+    F: FnMut(u8) -> u8,
+{
+    type Item = Guard<Play>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let standard = PlaySpec::standard(self.kind);
+        let search = &mut self.search;
+        while self.matches_kind && search.advance() {
+            if standard.primal_count.contains(&search.primal_count)
+                && (standard.kicker_count)(search.primal_count) == search.kicker_count
+            {
+                return Some(Guard(search.play(self.kind)));
+            }
+        }
+        None
+    }
+}
+
+/// Returns an iterator over the standard plays of the given kind in `hand`, as
+/// [`SearchExt::typed_plays`] with the standard spec of `kind`.
+/// 
+/// # Panics
+/// 
+/// Panics for `PlayKind::Rocket`, which cannot be represented by `PlaySpec`.
+pub(crate) fn standard_plays(hand: Hand, kind: PlayKind) -> TypedPlays<fn(u8) -> u8> {
+    TypedPlays { search: Search::new(hand, PlaySpec::standard(kind), 0), kind, matches_kind: true }
+}
+
+/// Returns an iterator over the standard plays of the given kind in `hand` whose
+/// primal elements are the `count` ranks starting at rank index `start`, in the
+/// order of [`SearchExt::typed_plays`].
+/// 
+/// Splitting a search into such windows allows it to run in parallel.
+#[cfg(feature = "rayon")]
+pub(crate) fn typed_plays_in_window(
+    hand: Hand,
+    kind: PlayKind,
+    count: u8,
+    start: u8,
+) -> impl Iterator<Item = Guard<Play>> {
+    let spec = PlaySpec { primal_count: count..=count, ..PlaySpec::standard(kind) };
+    let mut search = Search::new(hand, spec, start);
+    search.primal_ceiling = start;
+    TypedPlays { search, kind, matches_kind: true }
+}
+
+/// Returns the lowest and highest number of primal elements in `range` that a
+/// search can produce, which are at least 1 and at most 12 respectively.
+fn primal_count_bounds(range: &impl RangeBounds<u8>) -> (u8, u8) {
+    let min = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_add(1),
+        Bound::Unbounded => 1,
+    }
+    .max(1);
+
+    let max = match range.end_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.saturating_sub(1),
+        Bound::Unbounded => 12,
+    }
+    .min(12);
+
+    (min, max)
+}
+
+/// State of an enumeration of the plays in a hand matching a [`PlaySpec`].
+/// 
+/// Plays are produced in the following order: by ascending number of primal
+/// elements, then by ascending lowest primal rank, then by kickers. Kickers
+/// without a joker come first, in lexicographic order of their ranks, followed
+/// by those with the Black Joker and those with the Red Joker. The two jokers
+/// are never chosen together, since they would form a rocket.
+/// 
+/// All state lives in fixed-size buffers, so advancing never allocates.
+#[derive(Debug, Clone)]
+struct Search<F> {
+    counts: [u8; 15],
+    primal_size: u8,
+    kicker_size: u8,
+    kicker_count_fn: F,
+    primal_count: u8,
+    primal_count_max: u8,
+    /// Lowest rank allowed to start a primal window.
+    primal_floor: u8,
+    /// Highest rank allowed to start a primal window.
+    primal_ceiling: u8,
+    allow_unchainable_primal: bool,
+    /// Whether each rank is excluded from primal windows.
+    excluded: [bool; 15],
+    kicker_count: u8,
+    /// Lowest rank of the current primal window, if any.
+    start: Option<u8>,
+    /// Kicker candidates other than jokers, in ascending order.
+    candidates: [u8; 15],
+    num_candidates: u8,
+    jokers: [u8; 2],
+    num_jokers: u8,
+    /// `0` while enumerating kickers without jokers, `i` while enumerating
+    /// kickers with `jokers[i - 1]`.
+    joker_phase: u8,
+    /// Indices into `candidates` of the current combination.
+    combination: [u8; 15],
+    combination_len: u8,
+    /// Whether `combination` holds a combination yet.
+    combination_started: bool,
+}
+
+impl<F> Search<F>
+where
+    F: FnMut(u8) -> u8,
+{
+    fn new<R>(hand: Hand, spec: PlaySpec<R, F>, primal_floor: u8) -> Self
+    where
+        R: RangeBounds<u8>,
+    {
+        let (primal_count_min, primal_count_max) = primal_count_bounds(&spec.primal_count);
+
+        let primal_floor = match spec.primal_min {
+            Some(rank) => primal_floor.max(rank as u8),
+            None => primal_floor,
+        };
+
+        Self {
+            counts: hand.0,
+            primal_size: spec.primal_size,
+            kicker_size: spec.kicker_size,
+            kicker_count_fn: spec.kicker_count,
+            // `advance` moves on to `primal_count_min` first
+            primal_count: primal_count_min - 1,
+            primal_count_max,
+            primal_floor,
+            primal_ceiling: u8::MAX,
+            allow_unchainable_primal: spec.allow_unchainable_primal,
+            excluded: spec.excluded_ranks,
+            kicker_count: 0,
+            start: None,
+            candidates: [0; 15],
+            num_candidates: 0,
+            jokers: [0; 2],
+            num_jokers: 0,
+            joker_phase: 0,
+            combination: [0; 15],
+            combination_len: 0,
+            combination_started: false,
+        }
+    }
+
+    /// Moves on to the next play, returning `false` once the enumeration is exhausted.
+    fn advance(&mut self) -> bool {
+        loop {
+            if let Some(start) = self.start {
+                if self.next_kicker() {
+                    return true;
+                }
+                self.start = self.find_window(start + 1);
+            } else {
+                if self.primal_count >= self.primal_count_max {
+                    return false;
+                }
+                self.primal_count += 1;
+                self.kicker_count = (self.kicker_count_fn)(self.primal_count);
+                if self.kicker_count + self.primal_count > 15 {
+                    continue;
+                }
+                self.start = self.find_window(0);
+            }
+            if let Some(start) = self.start {
+                self.init_kickers(start);
+            }
+        }
+    }
+
+    /// Returns the lowest rank `>= from` starting a valid primal window.
+    fn find_window(&self, from: u8) -> Option<u8> {
+        let eligible = |rank: u8| {
+            self.counts[rank as usize] >= self.primal_size
+                && !self.excluded[rank as usize]
+                && (rank < Rank::Two as u8 || self.primal_count == 1 || self.allow_unchainable_primal)
+        };
+        (from.max(self.primal_floor)..=(15 - self.primal_count).min(self.primal_ceiling))
+            .find(|&start| (start..start + self.primal_count).all(eligible))
+    }
+
+    fn init_kickers(&mut self, start: u8) {
+        self.num_candidates = 0;
+        self.num_jokers = 0;
+        if self.kicker_count != 0 {
+            for rank in 0u8..15 {
+                if self.counts[rank as usize] >= self.kicker_size
+                    && !(start..start + self.primal_count).contains(&rank)
+                {
+                    if rank > Rank::Two as u8 {
+                        self.jokers[self.num_jokers as usize] = rank;
+                        self.num_jokers += 1;
+                    } else {
+                        self.candidates[self.num_candidates as usize] = rank;
+                        self.num_candidates += 1;
+                    }
                 }
-            })
-            .flat_map(move |(primal_count, kicker_count)| {
-                self.0
-                    .into_iter()
-                    .zip(0u8..15)
-                    .filter(|&(count, rank)| count >= spec.primal_size && (rank < Rank::Two as u8 || primal_count == 1))
-                    .map(|(_, rank)| unsafe { mem::transmute(rank) })
-                    .collect::<Vec<Rank>>()
-                    .chunk_by(|&a, &b| a as u8 + 1 == b as u8)
-                    .map(Vec::from)
-                    .collect::<Vec<_>>()
-                    .into_iter()
-                    .flat_map(move |chunk| {
-                        chunk
-                            .windows(primal_count as usize)
-                            .map(Vec::from)
-                            .collect::<Vec<_>>()
-                            .into_iter()
-                            .flat_map(move |primal| {
-                                let mut jokers = Vec::new();
-                                let kicker_candidates = if kicker_count != 0 {
-                                    self.0
-                                        .into_iter()
-                                        .zip(0u8..15)
-                                        .map(|(count, rank)| (count, unsafe { mem::transmute(rank) }))
-                                        .filter(|&(count, rank)| {
-                                            if count >= spec.kicker_size && !primal.contains(&rank) {
-                                                if rank > Rank::Two {
-                                                    jokers.push(rank);
-                                                    false
-                                                } else {
-                                                    true
-                                                }
-                                            } else {
-                                                false
-                                            }
-                                        })
-                                        .map(|(_, rank)| rank)
-                                        .collect::<Vec<Rank>>()
-                                } else {
-                                    Vec::new()
-                                };
-                                kicker_candidates
-                                    .clone()
-                                    .into_iter()
-                                    .combinations(kicker_count as usize)
-                                    .chain(
-                                        jokers
-                                            .into_iter()
-                                            .flat_map(move |joker| {
-                                                kicker_candidates
-                                                    .clone()
-                                                    .into_iter()
-                                                    .combinations(kicker_count as usize - 1)
-                                                    .map(move |mut kicker| {
-                                                        kicker.push(joker);
-                                                        kicker
-                                                    })
-                                            })
-                                    )
-                                    .map(move |kicker| {
-                                        let mut counts = [0u8; 15];
-                                        for rank in primal.clone() {
-                                            counts[rank as usize] = spec.primal_size;
-                                        }
-                                        for rank in kicker {
-                                            counts[rank as usize] = spec.kicker_size;
-                                        }
-                                        Hand(counts)
-                                    })
-                            })
-                    })
-            })
+            }
+        }
+        self.joker_phase = 0;
+        self.combination_len = self.kicker_count;
+        self.combination_started = false;
+    }
+
+    /// Moves on to the next kicker combination for the current primal window.
+    fn next_kicker(&mut self) -> bool {
+        loop {
+            if self.next_combination() {
+                return true;
+            }
+            if self.joker_phase >= self.num_jokers {
+                return false;
+            }
+            self.joker_phase += 1;
+            self.combination_len = self.kicker_count - 1;
+            self.combination_started = false;
+        }
+    }
+
+    /// Moves on to the next `combination_len`-combination of candidate indices.
+    fn next_combination(&mut self) -> bool {
+        let (n, k) = (self.num_candidates as usize, self.combination_len as usize);
+        if !self.combination_started {
+            if k > n {
+                return false;
+            }
+            for i in 0..k {
+                self.combination[i] = i as u8;
+            }
+            self.combination_started = true;
+            return true;
+        }
+        let Some(i) = (0..k).rev().find(|&i| (self.combination[i] as usize) < n - k + i) else {
+            return false;
+        };
+        self.combination[i] += 1;
+        for j in i + 1..k {
+            self.combination[j] = self.combination[j - 1] + 1;
+        }
+        true
+    }
+
+    /// Returns the ranks of the primal elements of the current play.
+    fn primal(&self) -> impl Iterator<Item = Rank> + use<F> {
+        let start = self.start.unwrap();
+        (start..start + self.primal_count).map(|rank| unsafe { mem::transmute::<u8, Rank>(rank) })
+    }
+
+    /// Returns the ranks of the kicker elements of the current play, in ascending order.
+    fn kicker(&self) -> impl Iterator<Item = Rank> + use<F> {
+        let (candidates, combination) = (self.candidates, self.combination);
+        let joker = self.joker_phase.checked_sub(1).map(|i| self.jokers[i as usize]);
+        (0..self.combination_len as usize)
+            .map(move |i| candidates[combination[i] as usize])
+            .chain(joker)
+            .map(|rank| unsafe { mem::transmute::<u8, Rank>(rank) })
+    }
+
+    /// Returns the current play as a play of the given kind.
+    /// 
+    /// Plays of a single primal rank are built directly from the buffers, and
+    /// the others allocate exactly the ranks they hold.
+    fn play(&self, kind: PlayKind) -> Play {
+        let start = unsafe { mem::transmute::<u8, Rank>(self.start.unwrap()) };
+        let mut kicker = self.kicker();
+        let mut next_kicker = || kicker.next().unwrap();
+        let kicker_vec = || {
+            let mut ranks = Vec::with_capacity(self.kicker_count as usize);
+            ranks.extend(self.combination[..self.combination_len as usize].iter().map(|&i| unsafe {
+                mem::transmute::<u8, Rank>(self.candidates[i as usize])
+            }));
+            if let Some(i) = self.joker_phase.checked_sub(1) {
+                ranks.push(unsafe { mem::transmute::<u8, Rank>(self.jokers[i as usize]) });
+            }
+            ranks
+        };
+        match kind {
+            PlayKind::Solo => Play::Solo(start),
+            PlayKind::Chain => Play::Chain(self.primal().collect()),
+            PlayKind::Pair => Play::Pair(start),
+            PlayKind::PairsChain => Play::PairsChain(self.primal().collect()),
+            PlayKind::Trio => Play::Trio(start),
+            PlayKind::Airplane => Play::Airplane(self.primal().collect()),
+            PlayKind::TrioWithSolo => Play::TrioWithSolo { trio: start, solo: next_kicker() },
+            PlayKind::AirplaneWithSolos => Play::AirplaneWithSolos { airplane: self.primal().collect(), solos: kicker_vec() },
+            PlayKind::TrioWithPair => Play::TrioWithPair { trio: start, pair: next_kicker() },
+            PlayKind::AirplaneWithPairs => Play::AirplaneWithPairs { airplane: self.primal().collect(), pairs: kicker_vec() },
+            PlayKind::Bomb => Play::Bomb(start),
+            PlayKind::FourWithDualSolo => Play::FourWithDualSolo { four: start, dual_solo: [next_kicker(), next_kicker()] },
+            PlayKind::FourWithDualPair => Play::FourWithDualPair { four: start, dual_pair: [next_kicker(), next_kicker()] },
+            PlayKind::Rocket => Play::Rocket,
+        }
+    }
+
+    /// Returns the cards of the current play.
+    fn hand(&self) -> Hand {
+        let mut counts = [0u8; 15];
+        for rank in self.primal() {
+            counts[rank as usize] = self.primal_size;
+        }
+        for rank in self.kicker() {
+            counts[rank as usize] = self.kicker_size;
+        }
+        Hand(counts)
     }
 }
@@ -0,0 +1,193 @@
+//! Counting the cards that have not been seen yet.
+//! 
+//! Seats are indexed from 0 to 2 as in [`score`](crate::score), though the
+//! landlord may sit at any index here.
+
+use core::{error::Error, fmt};
+use crate::{core::{Guard, UncheckedAddExt, UncheckedSubExt}, Hand, Play, Rank};
+
+/// Tracks the cards unseen by the player at one seat as a deal is played.
+/// 
+/// The unseen pool starts as the deck minus the player's own hand. Cards known
+/// to be held by another seat, such as the kitty taken by the landlord, are
+/// moved out of the pool and tracked per seat. A play by another seat uses up
+/// the cards known to be held by it first, then cards from the pool.
+/// 
+/// # Examples
+/// 
+/// Replaying the start of a deal from the seat of a peasant:
+/// 
+/// ```
+/// use dou_dizhu::{*, counter::CardCounter};
+/// 
+/// let mine = hand!(const {
+///     Three: 2, Five, Six, Seven, Eight, Nine: 2, Ten, Jack: 2, Queen, King: 3, Two, RedJoker,
+/// });
+/// let mut counter = CardCounter::new(1, mine);
+/// assert_eq!(counter.remaining().len(), 37);
+/// 
+/// // The landlord at seat 0 takes the kitty.
+/// counter.reveal_kitty(0, hand!(const { Four, Ace, Two })).unwrap();
+/// assert_eq!(counter.remaining().len(), 34);
+/// assert_eq!(counter.known(0), hand!(const { Four, Ace, Two }));
+/// assert_eq!(counter.possible(Rank::Two), 2);
+/// 
+/// // The landlord leads a pair of Fours, one of which is the known Four.
+/// counter.observe(0, &play!(const { Four: 2 }).unwrap()).unwrap();
+/// assert_eq!(counter.remaining().len(), 33);
+/// assert_eq!(counter.possible(Rank::Four), 2);
+/// assert_eq!(counter.known(0), hand!(const { Ace, Two }));
+/// 
+/// // I answer with a pair of Nines.
+/// counter.observe(1, &play!(const { Nine: 2 }).unwrap()).unwrap();
+/// assert_eq!(counter.remaining().len(), 33);
+/// assert_eq!(counter.hand(), (mine - hand!(const { Nine: 2 })).unwrap());
+/// 
+/// // The other peasant plays a pair of Twos over it.
+/// counter.observe(2, &play!(const { Two: 2 }).unwrap()).unwrap();
+/// assert_eq!(counter.remaining().len(), 31);
+/// assert_eq!(counter.possible(Rank::Two), 0);
+/// 
+/// // The landlord holds only the known Two, so the log is inconsistent.
+/// assert_eq!(
+///     counter.observe(0, &play!(const { Two: 2 }).unwrap()),
+///     Err(counter::CounterError::NotUnseen { rank: Rank::Two }),
+/// );
+/// assert_eq!(counter.remaining().len(), 31);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CardCounter {
+    seat: usize,
+    hand: Hand,
+    remaining: Hand,
+    known: [Hand; 3],
+}
+
+impl CardCounter {
+    /// Creates a counter for the player at `seat` holding `hand`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `seat` is not less than 3.
+    pub fn new(seat: usize, hand: Hand) -> Self {
+        assert!(seat < 3, "seat index out of range");
+        Self { seat, hand, remaining: hand.complement(), known: [Hand::EMPTY; 3] }
+    }
+
+    /// Reveals the kitty taken by the landlord at seat `landlord`.
+    /// 
+    /// If the landlord is the player, the kitty is added to their hand; otherwise
+    /// it is recorded as known to be held by the landlord. The counter is left
+    /// unmodified on failure.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`CounterError::NotUnseen`] if the kitty holds a card that is not
+    /// unseen.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `landlord` is not less than 3.
+    pub fn reveal_kitty(&mut self, landlord: usize, kitty: Hand) -> Result<(), CounterError> {
+        assert!(landlord < 3, "seat index out of range");
+        let remaining = self.take_unseen(kitty)?;
+        // The kitty was unseen, so it is disjoint from the cards of any seat.
+        if landlord == self.seat {
+            self.hand = unsafe { self.hand.unchecked_add(kitty) };
+        } else {
+            self.known[landlord] = unsafe { self.known[landlord].unchecked_add(kitty) };
+        }
+        self.remaining = remaining;
+        Ok(())
+    }
+
+    /// Records `play` made by the player at `seat`.
+    /// 
+    /// The counter is left unmodified on failure.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`CounterError::NotInHand`] if `seat` is the player's and the play
+    /// uses a card not in their hand, or [`CounterError::NotUnseen`] if `seat` is
+    /// another player's and the play uses a card that is neither known to be held
+    /// by them nor unseen.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `seat` is not less than 3.
+    pub fn observe(&mut self, seat: usize, play: &Guard<Play>) -> Result<(), CounterError> {
+        assert!(seat < 3, "seat index out of range");
+        let cards = play.to_hand();
+        if seat == self.seat {
+            self.hand = take(self.hand, cards).map_err(|rank| CounterError::NotInHand { rank })?;
+        } else {
+            let from_known = self.known[seat].intersection(cards);
+            self.remaining = self.take_unseen(cards.saturating_sub(from_known))?;
+            self.known[seat] = self.known[seat].saturating_sub(from_known);
+        }
+        Ok(())
+    }
+
+    /// Returns the cards still held by the player.
+    pub const fn hand(&self) -> Hand {
+        self.hand
+    }
+
+    /// Returns the unseen cards, i.e. the cards held by the other players that
+    /// are not known to be held by them.
+    pub const fn remaining(&self) -> Hand {
+        self.remaining
+    }
+
+    /// Returns the number of unseen cards of `rank`.
+    pub fn possible(&self, rank: Rank) -> u8 {
+        self.remaining[rank]
+    }
+
+    /// Returns the cards known to be held by the player at `seat`.
+    /// 
+    /// For the player's own seat, this is [`hand`](CardCounter::hand).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `seat` is not less than 3.
+    pub fn known(&self, seat: usize) -> Hand {
+        assert!(seat < 3, "seat index out of range");
+        if seat == self.seat { self.hand } else { self.known[seat] }
+    }
+
+    fn take_unseen(&self, cards: Hand) -> Result<Hand, CounterError> {
+        take(self.remaining, cards).map_err(|rank| CounterError::NotUnseen { rank })
+    }
+}
+
+/// Removes `cards` from `hand`, returning the first rank of which `hand` holds
+/// too few cards on failure.
+fn take(hand: Hand, cards: Hand) -> Result<Hand, Rank> {
+    if let Some(rank) = Rank::iter().find(|&rank| hand[rank] < cards[rank]) {
+        return Err(rank);
+    }
+    Ok(unsafe { hand.unchecked_sub(cards) })
+}
+
+/// Error returned by [`CardCounter`] for observations inconsistent with the
+/// cards seen so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CounterError {
+    /// A card of `rank` was played or revealed by another seat, but no card of
+    /// `rank` is unseen.
+    NotUnseen { rank: Rank },
+    /// A card of `rank` was played by the player, but their hand holds none.
+    NotInHand { rank: Rank },
+}
+
+impl fmt::Display for CounterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotUnseen { rank } => write!(f, "no unseen `{:?}` left to be played", rank),
+            Self::NotInHand { rank } => write!(f, "not enough `{:?}`s in hand", rank),
+        }
+    }
+}
+
+impl Error for CounterError {}
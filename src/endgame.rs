@@ -0,0 +1,132 @@
+//! Solving endgames with perfect information.
+//! 
+//! When the cards of every player are known, whether the player to move can
+//! force a win is decided by searching the whole game tree.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use crate::{core::Guard, Hand, Play};
+
+/// Returns `true` if the player `to_move` can force a win, i.e. empty their hand
+/// first, whatever the other players do.
+/// 
+/// `hands` holds the hands of the players in turn order, so that `hands[i + 1]`
+/// plays after `hands[i]` and `hands[0]` after the last one. With two hands, these
+/// are e.g. the landlord and the union of the cards of the peasants. With three or
+/// more, the other players are assumed to play together against `to_move`, which is
+/// the actual game for the landlord and a conservative estimate for a peasant,
+/// whose partner emptying their hand first counts as a loss.
+/// 
+/// If `lead` is given, it is the play on the table, made by the player before
+/// `to_move`, which the player to move must beat or pass on. A trick ends when
+/// play comes back to the player who made the last play, all others having
+/// passed, and that player then leads a new trick. A player whose hand is already
+/// empty has won.
+/// 
+/// Positions are memoized on the packed hands of the players, whose turn it is,
+/// who made the play on the table and that play.
+/// 
+/// # Panics
+/// 
+/// Panics if there are fewer than two hands or `to_move` is out of range.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, endgame::solve_endgame};
+/// 
+/// // A pair of Twos wins against a single Ace.
+/// let hands = [hand!(const { Two: 2 }), hand!(const { Ace })];
+/// assert!(solve_endgame(&hands, 0, None));
+/// 
+/// // Three low singles lose to a single Two, which beats the first lead.
+/// let hands = [hand!(const { Three, Five, Seven }), hand!(const { Two })];
+/// assert!(!solve_endgame(&hands, 0, None));
+/// 
+/// // Passing on the Ace lets the opponent win with its last card.
+/// let ace = play!(const { Ace }).unwrap();
+/// let hands = [hand!(const { Three, Two }), hand!(const { Four })];
+/// assert!(solve_endgame(&hands, 0, Some(&ace)));
+/// let hands = [hand!(const { Three, King }), hand!(const { Four })];
+/// assert!(!solve_endgame(&hands, 0, Some(&ace)));
+/// 
+/// // Leading the Five and then the Three wins against a single Four...
+/// let hands = [hand!(const { Three, Five }), hand!(const { Four })];
+/// assert!(solve_endgame(&hands, 0, None));
+/// // ...but not once a second opponent holds a Six.
+/// let hands = [hand!(const { Three, Five }), hand!(const { Four }), hand!(const { Six })];
+/// assert!(!solve_endgame(&hands, 0, None));
+/// 
+/// // The Two goes around the table unbeaten, so its player leads the Three next.
+/// let hands = [hand!(const { Four, Five }), hand!(const { Three, Two }), hand!(const { Ace })];
+/// assert!(solve_endgame(&hands, 1, None));
+/// assert!(!solve_endgame(&hands, 0, None));
+/// 
+/// // The Ace was played by the last seat, which leads its King after two passes.
+/// let hands = [hand!(const { Three, King }), hand!(const { Four }), hand!(const { King })];
+/// assert!(!solve_endgame(&hands, 0, Some(&ace)));
+/// let hands = [hand!(const { Three, Two }), hand!(const { Four }), hand!(const { King })];
+/// assert!(solve_endgame(&hands, 0, Some(&ace)));
+/// ```
+pub fn solve_endgame(hands: &[Hand], to_move: usize, lead: Option<&Guard<Play>>) -> bool {
+    let n = hands.len();
+    assert!(n >= 2, "an endgame needs at least two players");
+    assert!(to_move < n, "player index out of range");
+    if hands.iter().enumerate().any(|(i, hand)| i != to_move && hand.is_empty()) {
+        return false;
+    }
+    if hands[to_move].is_empty() {
+        return true;
+    }
+    // Seats are renumbered so that the player to move is seat 0.
+    let mut seats = (0..n).map(|i| hands[(to_move + i) % n]).collect::<Vec<_>>();
+    let leader = if lead.is_some() { n - 1 } else { 0 };
+    wins(&mut seats, 0, lead, leader, &mut BTreeMap::new())
+}
+
+/// Results of [`wins`], keyed on the packed hands, the seat to move, the seat
+/// that made the play on the table and that play.
+type Memo = BTreeMap<(Vec<u64>, usize, usize, Option<Play>), bool>;
+
+/// Returns `true` if seat 0 can force a win when seat `turn` is to move and the
+/// play `lead`, if any, was made by seat `leader`, all hands being non-empty.
+fn wins(
+    seats: &mut [Hand],
+    turn: usize,
+    lead: Option<&Guard<Play>>,
+    leader: usize,
+    memo: &mut Memo,
+) -> bool {
+    let key = (
+        seats.iter().copied().map(Hand::to_packed).collect::<Vec<_>>(),
+        turn,
+        leader,
+        lead.map(|x| x.0.clone()),
+    );
+    if let Some(&result) = memo.get(&key) {
+        return result;
+    }
+    let next = (turn + 1) % seats.len();
+    let hand = seats[turn];
+    // Seat 0 needs one winning move, the other seats one move that defeats it.
+    let mut outcome = |seats: &mut [Hand], play: Option<(Guard<Play>, Hand)>| match play {
+        Some((_, rest)) if rest.is_empty() => turn == 0,
+        Some((play, rest)) => {
+            seats[turn] = rest;
+            let result = wins(seats, next, Some(&play), turn, memo);
+            seats[turn] = hand;
+            result
+        }
+        // Passing ends the recursion once every other seat has passed, since a
+        // new lead removes cards.
+        None if next == leader => wins(seats, next, None, next, memo),
+        None => wins(seats, next, lead, leader, memo),
+    };
+    let mut moves = hand.children(lead).map(Some).chain(lead.map(|_| None));
+    let result = if turn == 0 {
+        moves.any(|x| outcome(seats, x))
+    } else {
+        moves.all(|x| outcome(seats, x))
+    };
+    memo.insert(key, result);
+    result
+}
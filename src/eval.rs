@@ -0,0 +1,244 @@
+//! Heuristic evaluation of hands.
+//! 
+//! An [`Evaluator`] estimates how strong a hand is, e.g. to decide how high to
+//! bid on a dealt hand. Scores are only meaningful relative to other scores from
+//! the same evaluator.
+//! 
+//! [`Hand::hand_strength`] is an integer score weighting the
+//! [`StrengthComponents`] of a hand, which also account for how many plays the
+//! hand takes to play out. [`ComponentEvaluator`] is the same score as an
+//! [`Evaluator`], so it can be used wherever [`DefaultEvaluator`] is.
+
+use crate::{Hand, Rank};
+
+/// A heuristic estimate of the strength of a hand.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, eval::Evaluator};
+/// 
+/// /// Counts the cards of rank Ace or higher.
+/// struct HighCards;
+/// 
+/// impl Evaluator for HighCards {
+///     fn evaluate(&self, hand: &Hand) -> f32 {
+///         Rank::iter().filter(|&rank| rank >= Rank::Ace).map(|rank| hand[rank] as f32).sum()
+///     }
+/// }
+/// 
+/// assert_eq!(HighCards.evaluate(&hand!(const { Three, Ace: 2, RedJoker })), 3.0);
+/// ```
+pub trait Evaluator {
+    /// Returns the strength of `hand`, higher being stronger.
+    fn evaluate(&self, hand: &Hand) -> f32;
+}
+
+/// The evaluator used by [`Hand::strength`].
+/// 
+/// The score of a hand is the sum of:
+/// 
+/// - 8 for the rocket, or 3 for the Black Joker and 4 for the Red Joker alone,
+/// - 6 for each bomb,
+/// - 2 for each Two,
+/// - 1 for each trio of rank Jack to Ace,
+/// 
+/// minus 1 for each isolated low single, i.e. a single card of rank Three to Ten
+/// with no card of either neighbouring rank, which is hard to get rid of.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultEvaluator;
+
+impl Evaluator for DefaultEvaluator {
+    fn evaluate(&self, hand: &Hand) -> f32 {
+        let jokers = match (hand[Rank::BlackJoker], hand[Rank::RedJoker]) {
+            (1, 1) => 8.0,
+            (1, 0) => 3.0,
+            (0, 1) => 4.0,
+            _ => 0.0,
+        };
+        let bombs = hand.count_by_multiplicity()[4] as f32 * 6.0;
+        let twos = hand[Rank::Two] as f32 * 2.0;
+        let high_trios = (Rank::Jack as usize..=Rank::Ace as usize)
+            .filter(|&i| hand.0[i] == 3)
+            .count() as f32;
+        let isolated = (Rank::Three as usize..=Rank::Ten as usize)
+            .filter(|&i| {
+                hand.0[i] == 1
+                    && (i == 0 || hand.0[i - 1] == 0)
+                    && hand.0[i + 1] == 0
+            })
+            .count() as f32;
+        jokers + bombs + twos + high_trios - isolated
+    }
+}
+
+impl Hand {
+    /// Returns the strength of this hand as estimated by [`DefaultEvaluator`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let strong = hand!(const { Three, Five, Seven: 4, Jack, BlackJoker, RedJoker });
+    /// let weak = hand!(const { Three, Five, Seven, Nine, Jack, King: 2, Ace, Two });
+    /// assert!(strong.strength() > weak.strength());
+    /// 
+    /// // Even the strongest dealt hand falls short of the whole deck.
+    /// let best = hand!(const { Queen: 3, King: 4, Ace: 4, Two: 4, BlackJoker, RedJoker });
+    /// assert_eq!(best.len(), 17);
+    /// assert!(Hand::FULL_DECK.strength() > best.strength());
+    /// ```
+    pub fn strength(&self) -> f32 {
+        DefaultEvaluator.evaluate(self)
+    }
+}
+
+/// The evaluator scoring a hand by its [`StrengthComponents`], as
+/// [`Hand::hand_strength`] does.
+/// 
+/// Unlike [`DefaultEvaluator`], it accounts for how many plays the hand takes to
+/// play out, so the two may rank hands differently.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, eval::{ComponentEvaluator, DefaultEvaluator, Evaluator}};
+/// 
+/// let chain = hand!(const { Three, Four, Five, Six, Seven, Eight, Nine, Ten });
+/// let high = hand!(const { Jack, King, Ace, Two: 2 });
+/// assert_eq!(ComponentEvaluator.evaluate(&chain), chain.hand_strength() as f32);
+/// 
+/// // The chain takes a single play, but has no Two.
+/// assert!(ComponentEvaluator.evaluate(&chain) > ComponentEvaluator.evaluate(&high));
+/// assert!(DefaultEvaluator.evaluate(&chain) < DefaultEvaluator.evaluate(&high));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComponentEvaluator;
+
+impl Evaluator for ComponentEvaluator {
+    fn evaluate(&self, hand: &Hand) -> f32 {
+        hand.hand_strength() as f32
+    }
+}
+
+/// The features of a hand weighted by [`Hand::hand_strength`], for building
+/// other weightings.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// let hand = hand!(const {
+///     Three, Four, Five, Six, Seven, Eight, Ten: 4, King, Ace: 2, Two: 2, BlackJoker, RedJoker,
+/// });
+/// let components = hand.strength_components();
+/// assert!(components.rocket);
+/// assert_eq!(components.bombs, 1);
+/// assert_eq!(components.twos, 2);
+/// assert_eq!(components.high_cards, 3);
+/// assert_eq!(components.longest_chain, 6);
+/// assert_eq!(components.min_plays, 4);
+/// 
+/// // A weighting favouring Twos.
+/// let score = components.twos as i32 * 10 - components.min_plays as i32;
+/// assert_eq!(score, 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StrengthComponents {
+    /// Whether the hand holds the rocket.
+    pub rocket: bool,
+    /// The number of bombs.
+    pub bombs: u32,
+    /// The number of Twos.
+    pub twos: u32,
+    /// The number of Kings and Aces, plus a joker without the other one.
+    pub high_cards: u32,
+    /// The length of the longest chain, or 0 if the hand holds none.
+    pub longest_chain: u32,
+    /// The [fewest plays](Hand::min_play_count) the hand can be played out in.
+    pub min_plays: u32,
+}
+
+impl StrengthComponents {
+    /// Returns the score given by [`Hand::hand_strength`] to a hand with these
+    /// components.
+    /// 
+    /// The score is the sum of:
+    /// 
+    /// - 16 for the rocket,
+    /// - 12 for each bomb,
+    /// - 3 for each Two,
+    /// - 1 for each high card,
+    /// - 1 for each card of the longest chain,
+    /// 
+    /// minus 2 for each play needed to play out the hand.
+    /// 
+    /// Since a bomb or the rocket scores more than the play it takes, adding one
+    /// to a hand never lowers its score.
+    pub const fn score(&self) -> i32 {
+        let rocket = if self.rocket { 16 } else { 0 };
+        rocket
+            + self.bombs as i32 * 12
+            + self.twos as i32 * 3
+            + self.high_cards as i32
+            + self.longest_chain as i32
+            - self.min_plays as i32 * 2
+    }
+}
+
+impl Hand {
+    /// Returns the features of this hand weighted by
+    /// [`hand_strength`](Hand::hand_strength).
+    /// 
+    /// See [`StrengthComponents`] for an example.
+    pub fn strength_components(&self) -> StrengthComponents {
+        let rocket = self[Rank::BlackJoker] == 1 && self[Rank::RedJoker] == 1;
+        let lone_joker = (self[Rank::BlackJoker] + self[Rank::RedJoker] == 1) as u32;
+        let mut longest_chain = 0;
+        let mut run = 0;
+        for rank in Rank::iter().take_while(|&rank| rank <= Rank::Ace) {
+            run = if self[rank] > 0 { run + 1 } else { 0 };
+            longest_chain = longest_chain.max(run);
+        }
+        StrengthComponents {
+            rocket,
+            bombs: self.count_by_multiplicity()[4] as u32,
+            twos: self[Rank::Two] as u32,
+            high_cards: (self[Rank::King] + self[Rank::Ace]) as u32 + lone_joker,
+            longest_chain: if longest_chain >= 5 { longest_chain } else { 0 },
+            min_plays: self.min_play_count() as u32,
+        }
+    }
+
+    /// Returns a heuristic score of the strength of this hand, e.g. for bidding.
+    /// 
+    /// This is the [score](StrengthComponents::score) of the
+    /// [components](Hand::strength_components) of this hand, as estimated by
+    /// [`ComponentEvaluator`]. Unlike [`strength`](Hand::strength), it accounts
+    /// for how many plays the hand takes to play out. The score is deterministic,
+    /// and adding a bomb or the rocket to a hand never lowers it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let strong = hand!(const { Three, Four, Five, Six, Seven, Nine: 4, Two: 2, BlackJoker, RedJoker });
+    /// let weak = hand!(const { Three, Five, Seven, Nine, Jack, King: 2, Ace, Two });
+    /// assert!(strong.hand_strength() > weak.hand_strength());
+    /// 
+    /// for rank in Rank::iter().take(13) {
+    ///     let bomb = Hand::EMPTY.set_count(rank, 4).unwrap();
+    ///     if let Some(stronger) = weak + bomb {
+    ///         assert!(stronger.hand_strength() > weak.hand_strength());
+    ///     }
+    /// }
+    /// let rocket = hand!(const { BlackJoker, RedJoker });
+    /// assert!((weak + rocket).unwrap().hand_strength() > weak.hand_strength());
+    /// ```
+    pub fn hand_strength(&self) -> i32 {
+        self.strength_components().score()
+    }
+}
@@ -1,22 +1,46 @@
-use std::{iter, mem, ops::Index};
-use crate::{core::{CompositionExt, Guard, PlaySpec, SearchExt}, Play, PlayKind, Rank};
+use alloc::{collections::{BTreeMap, BTreeSet}, string::{String, ToString}, vec::Vec};
+use core::{cmp::{Ordering, Reverse}, error::Error, fmt, hash::{Hash, Hasher}, iter, mem, ops::Index, option, slice};
+use crate::{core::{search::{self, TypedPlays}, CompositionExt, Guard, PlaySpec, SearchExt, UncheckedSubExt}, Card, Play, PlayKind, Rank};
 
 /// Representation of a Dou Dizhu hand.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Hand(pub(crate) [u8; 15]);
 
 impl TryFrom<[u8; 15]> for Hand {
-    type Error = String;
+    type Error = HandError;
 
+    /// Creates a hand from the card counts of each rank.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let mut counts = [0; 15];
+    /// counts[Rank::Three as usize] = 5;
+    /// assert_eq!(
+    ///     Hand::try_from(counts),
+    ///     Err(HandError::TooManyOfRank { rank: Rank::Three, count: 5 }),
+    /// );
+    /// 
+    /// let mut counts = [0; 15];
+    /// counts[Rank::RedJoker as usize] = 2;
+    /// assert_eq!(
+    ///     Hand::try_from(counts),
+    ///     Err(HandError::TooManyJokers { rank: Rank::RedJoker, count: 2 }),
+    /// );
+    /// ```
     fn try_from(counts: [u8; 15]) -> Result<Self, Self::Error> {
         for i in 0u8..13 {
             if counts[i as usize] > 4 {
-                return Err(format!("more than four `{:?}`s are specified", unsafe { mem::transmute::<_, Rank>(i) }));
+                let rank = unsafe { mem::transmute::<u8, Rank>(i) };
+                return Err(HandError::TooManyOfRank { rank, count: counts[i as usize] });
             }
         }
         for i in 13u8..15 {
             if counts[i as usize] > 1 {
-                return Err(format!("more than one `{:?}` is specified", unsafe { mem::transmute::<_, Rank>(i) }));
+                let rank = unsafe { mem::transmute::<u8, Rank>(i) };
+                return Err(HandError::TooManyJokers { rank, count: counts[i as usize] });
             }
         }
         Ok(Hand(counts))
@@ -24,11 +48,21 @@ impl TryFrom<[u8; 15]> for Hand {
 }
 
 impl TryFrom<&[u8]> for Hand {
-    type Error = String;
+    type Error = HandError;
 
+    /// Creates a hand from a slice of the card counts of each rank.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Hand::try_from(&[0; 14][..]), Err(HandError::InvalidLength { got: 14 }));
+    /// assert_eq!(Hand::try_from(&[0; 15][..]), Ok(Hand::EMPTY));
+    /// ```
     fn try_from(counts: &[u8]) -> Result<Self, Self::Error> {
         if counts.len() != 15 {
-            return Err(format!("invalid slice length: expected 15, got {}", counts.len()));
+            return Err(HandError::InvalidLength { got: counts.len() });
         }
         <Hand as TryFrom<[u8; 15]>>::try_from(counts.try_into().unwrap())
     }
@@ -58,6 +92,78 @@ impl Hand {
         self.0
     }
 
+    /// Returns this hand packed into a `u64`.
+    /// 
+    /// The layout is stable: the count of the rank with discriminant `i` (see [`Rank`])
+    /// occupies the three bits starting at bit `3 * i`, so the ranks from
+    /// `Three` to `RedJoker` use bits 0 to 44, and the remaining bits are zero.
+    /// Two hands are equal if and only if their packed values are equal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(hand!(const { Three: 2, Four, RedJoker }).to_packed(), 2 | 1 << 3 | 1 << 42);
+    /// assert_eq!(Hand::EMPTY.to_packed(), 0);
+    /// ```
+    pub const fn to_packed(self) -> u64 {
+        let mut packed = 0;
+        {
+            let mut i = 0;
+            while i < 15 {
+                packed |= (self.0[i] as u64) << (3 * i);
+                i += 1;
+            }
+        }
+        packed
+    }
+
+    /// Unpacks a hand from the layout of [`to_packed`](Hand::to_packed).
+    /// 
+    /// Fails if a bit above bit 44 is set or if a count exceeds the number of
+    /// such cards in a deck.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// // Round trip over pseudo-random valid hands
+    /// let mut state = 0x2545_f491_4f6c_dd1du64;
+    /// for _ in 0..1000 {
+    ///     let mut counts = [0u8; 15];
+    ///     for (i, count) in counts.iter_mut().enumerate() {
+    ///         state ^= state << 13;
+    ///         state ^= state >> 7;
+    ///         state ^= state << 17;
+    ///         *count = (state % if i < 13 { 5 } else { 2 }) as u8;
+    ///     }
+    ///     let hand = Hand::try_from(counts).unwrap();
+    ///     assert_eq!(Hand::from_packed(hand.to_packed()), Ok(hand));
+    /// }
+    /// 
+    /// assert_eq!(
+    ///     Hand::from_packed(5),
+    ///     Err(HandError::TooManyOfRank { rank: Rank::Three, count: 5 }),
+    /// );
+    /// assert_eq!(
+    ///     Hand::from_packed(2 << 42),
+    ///     Err(HandError::TooManyJokers { rank: Rank::RedJoker, count: 2 }),
+    /// );
+    /// assert_eq!(Hand::from_packed(1 << 45), Err(HandError::InvalidPacked { packed: 1 << 45 }));
+    /// ```
+    pub fn from_packed(packed: u64) -> Result<Hand, HandError> {
+        if packed >> 45 != 0 {
+            return Err(HandError::InvalidPacked { packed });
+        }
+        let mut counts = [0u8; 15];
+        for (i, count) in counts.iter_mut().enumerate() {
+            *count = (packed >> (3 * i) & 0b111) as u8;
+        }
+        Hand::try_from(counts)
+    }
+
     /// Attempts to recognize this `Hand` as a standard [`Play`].
     /// 
     /// Returns `None` if the hand does not form a standard play.
@@ -77,6 +183,109 @@ impl Hand {
         self.composition().guess_play()
     }
 
+    /// Attempts to recognize this `Hand` as a standard [`Play`] in a const context.
+    /// 
+    /// This agrees with [`to_play`](Self::to_play) except for the kinds whose ranks
+    /// are stored in a `Vec`, which cannot be built at compile time. These are
+    /// exactly the kinds for which [`PlayKind::is_chain_type`] is `true`: `Chain`,
+    /// `PairsChain`, `Airplane`, `AirplaneWithSolos` and `AirplaneWithPairs`. For
+    /// hands forming such a play, `None` is returned, as for hands forming no play;
+    /// use [`to_play`](Self::to_play) outside const contexts to tell them apart.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// const ROCKET: Option<Play> = hand!(const { BlackJoker, RedJoker }).to_play_const();
+    /// assert_eq!(ROCKET, Some(Play::Rocket));
+    /// 
+    /// // Every play is recognized unless its kind is chain-like.
+    /// for &kind in PlayKind::all() {
+    ///     for play in Hand::FULL_DECK.plays(kind) {
+    ///         let expected = (!kind.is_chain_type()).then(|| play.clone().into_inner());
+    ///         assert_eq!(play.to_hand().to_play_const(), expected, "{kind:?}");
+    ///     }
+    /// }
+    /// 
+    /// assert_eq!(hand!(const { Three, Four, Five, Six, Seven }).to_play_const(), None);
+    /// assert_eq!(hand!(const { Three: 4, BlackJoker, RedJoker }).to_play_const(), None);
+    /// ```
+    pub const fn to_play_const(self) -> Option<Play> {
+        // Ranks of each multiplicity, in ascending order.
+        let mut groups = [[Rank::Three; 15]; 5];
+        let mut lens = [0; 5];
+        let mut i = 0;
+        while i < 15 {
+            let count = self.0[i] as usize;
+            if count != 0 {
+                groups[count][lens[count]] = unsafe { mem::transmute::<u8, Rank>(i as u8) };
+                lens[count] += 1;
+            }
+            i += 1;
+        }
+        let [_, solos, pairs, trios, fours] = groups;
+        let is_rocket = lens[1] == 2 && solos[0] as u8 == Rank::BlackJoker as u8;
+        match lens {
+            [_, 1, 0, 0, 0] => Some(Play::Solo(solos[0])),
+            [_, 0, 1, 0, 0] => Some(Play::Pair(pairs[0])),
+            [_, 0, 0, 1, 0] => Some(Play::Trio(trios[0])),
+            [_, 1, 0, 1, 0] => Some(Play::TrioWithSolo { trio: trios[0], solo: solos[0] }),
+            [_, 0, 1, 1, 0] => Some(Play::TrioWithPair { trio: trios[0], pair: pairs[0] }),
+            [_, 0, 0, 0, 1] => Some(Play::Bomb(fours[0])),
+            [_, 2, 0, 0, 1] if !is_rocket => Some(Play::FourWithDualSolo {
+                four: fours[0],
+                dual_solo: [solos[0], solos[1]],
+            }),
+            [_, 0, 2, 0, 1] => Some(Play::FourWithDualPair {
+                four: fours[0],
+                dual_pair: [pairs[0], pairs[1]],
+            }),
+            [_, 2, 0, 0, 0] if is_rocket => Some(Play::Rocket),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this hand can be played out in a single standard play.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(hand!(const { Three: 3, Four: 2 }).can_finish_in_one_play());
+    /// assert!(!hand!(const { Three: 3, Four, Five }).can_finish_in_one_play());
+    /// ```
+    pub fn can_finish_in_one_play(self) -> bool {
+        self.to_play().is_some()
+    }
+
+    /// Returns all standard plays that use up exactly the cards in this hand.
+    /// 
+    /// This searches every play kind whose size matches the hand. Since kickers
+    /// never share a rank with the primal cards or with each other, a hand has at
+    /// most one such play, and the result agrees with [`to_play`](Self::to_play).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four: 3, Five: 3, Six: 3 });
+    /// let plays = hand.plays_that_empty_hand();
+    /// 
+    /// assert_eq!(plays, [hand.to_play().unwrap()]);
+    /// assert!(plays.iter().all(|play| play.to_hand() == hand));
+    /// assert!(hand!(const { Three, Four }).plays_that_empty_hand().is_empty());
+    /// ```
+    pub fn plays_that_empty_hand(self) -> Vec<Guard<Play>> {
+        PlayKind::candidates_for_size(self.len())
+            .iter()
+            .flat_map(|&kind| self.plays(kind))
+            .filter(|play| play.to_hand() == self)
+            .collect()
+    }
+
     /// Returns an iterator over all standard plays of the given kind available in this hand.
     /// 
     /// # Examples
@@ -89,75 +298,1369 @@ impl Hand {
     ///     7516,
     /// )
     /// ```
-    pub fn plays(self, kind: PlayKind) -> impl Iterator<Item = Guard<Play>> {
-        match kind {
+    pub fn plays(self, kind: PlayKind) -> Plays {
+        Plays(match kind {
             PlayKind::Rocket => {
-                if self.0[Rank::BlackJoker as usize] == 1
-                    && self.0[Rank::RedJoker as usize] == 1
-                {
-                    Box::new([Guard(Play::Rocket)].into_iter())
-                } else {
-                    Box::new(iter::empty()) as Box<dyn Iterator<Item = Guard<Play>>>
-                }
+                let has_rocket = self.0[Rank::BlackJoker as usize] == 1
+                    && self.0[Rank::RedJoker as usize] == 1;
+                PlaysInner::Rocket(has_rocket.then_some(Guard(Play::Rocket)).into_iter())
+            }
+            kind => PlaysInner::Search(search::standard_plays(self, kind)),
+        })
+    }
+
+    /// Returns all standard plays of the given kind available in this hand, from
+    /// weakest to strongest.
+    /// 
+    /// Plays are sorted by [chain length](Guard::chain_length) first, since
+    /// chain-like plays of different lengths are incomparable, and then by strength
+    /// as compared by the [`PartialOrd`] implementation of [`Guard<Play>`]. Plays of
+    /// equal strength, which differ only in their kickers, keep the order of
+    /// [`plays`](Hand::plays).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 2, Four: 2, Five: 2, Six: 2, Nine: 2, Two: 2 });
+    /// let pairs = hand.plays_sorted_by_strength(Pair);
+    /// assert!(pairs.windows(2).all(|w| w[0] < w[1]));
+    /// assert_eq!(pairs.last().unwrap().leading_rank(), Rank::Two);
+    /// 
+    /// let chains = hand.plays_sorted_by_strength(PairsChain);
+    /// let chains = chains.iter().map(|x| x.to_notation()).collect::<Vec<_>>();
+    /// assert_eq!(chains, ["334455", "445566", "33445566"]);
+    /// 
+    /// let trios = Hand::FULL_DECK.plays_sorted_by_strength(TrioWithSolo);
+    /// assert!(trios.windows(2).all(|w| w[0] <= w[1]));
+    /// ```
+    pub fn plays_sorted_by_strength(self, kind: PlayKind) -> Vec<Guard<Play>> {
+        let mut plays = self.plays(kind).collect::<Vec<_>>();
+        plays.sort_by(|a, b| {
+            a.chain_length()
+                .cmp(&b.chain_length())
+                .then_with(|| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+        });
+        plays
+    }
+
+    /// Returns all standard plays of the given kind available in this hand, in
+    /// the reverse order of [`plays_sorted_by_strength`](Hand::plays_sorted_by_strength).
+    /// 
+    /// The longest chains thus come first, from strongest to weakest.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 4, Seven: 3, Queen, Ace: 4 });
+    /// let trios = hand.plays_sorted_strongest_first(Trio);
+    /// let ranks = trios.iter().map(|x| x.leading_rank()).collect::<Vec<_>>();
+    /// assert_eq!(ranks, [Rank::Ace, Rank::Seven, Rank::Three]);
+    /// 
+    /// let chains = Hand::FULL_DECK.plays_sorted_strongest_first(Chain);
+    /// assert_eq!(chains[0].chain_length(), Some(12));
+    /// assert!(chains.windows(2).all(|w| w[0].chain_length() > w[1].chain_length() || w[0] > w[1]));
+    /// ```
+    pub fn plays_sorted_strongest_first(self, kind: PlayKind) -> Vec<Guard<Play>> {
+        let mut plays = self.plays_sorted_by_strength(kind);
+        plays.reverse();
+        plays
+    }
+
+    /// Adds `rhs` to this hand in place.
+    /// 
+    /// On failure, the hand is left unmodified and the error identifies the
+    /// first rank whose count would exceed the number of such cards in a deck.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let mut hand = hand!(const { Three: 3 });
+    /// hand.try_add_assign(hand!(const { Three })).unwrap();
+    /// assert_eq!(hand, hand!(const { Three: 4 }));
+    /// 
+    /// assert_eq!(
+    ///     hand.try_add_assign(hand!(const { Four, Three })),
+    ///     Err(HandArithmeticError::Overflow { rank: Rank::Three }),
+    /// );
+    /// assert_eq!(hand, hand!(const { Three: 4 }));
+    /// ```
+    pub fn try_add_assign(&mut self, rhs: impl Into<Hand>) -> Result<(), HandArithmeticError> {
+        let rhs = rhs.into();
+        let mut counts = self.0;
+        for (i, rank) in Rank::iter().enumerate() {
+            counts[i] += rhs.0[i];
+            if counts[i] > rank.max_count() {
+                return Err(HandArithmeticError::Overflow { rank });
             }
-            kind => Box::new(
-                SearchExt::plays(self, PlaySpec::standard(kind))
-                    .map(move |x| x.composition().to_play(kind).unwrap()),
-            ),
         }
+        self.0 = counts;
+        Ok(())
     }
 
-    /// Returns the total number of cards in this hand.
+    /// Removes `rhs` from this hand in place.
+    /// 
+    /// On failure, the hand is left unmodified and the error identifies the
+    /// first rank of which this hand holds fewer cards than `rhs`.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use dou_dizhu::*;
     /// 
+    /// let mut hand = hand!(const { Three: 4, Four });
+    /// let bomb = play!(const { Three: 4 }).unwrap();
+    /// hand.try_sub_assign(&bomb).unwrap();
+    /// assert_eq!(hand, hand!(const { Four }));
+    /// 
     /// assert_eq!(
-    ///     Hand::FULL_DECK.len(),
-    ///     54,
-    /// )
+    ///     hand.try_sub_assign(&bomb),
+    ///     Err(HandArithmeticError::Underflow { rank: Rank::Three }),
+    /// );
+    /// assert_eq!(hand, hand!(const { Four }));
     /// ```
-    pub const fn len(&self) -> usize {
-        let mut sum = 0;
+    pub fn try_sub_assign(&mut self, rhs: impl Into<Hand>) -> Result<(), HandArithmeticError> {
+        let rhs = rhs.into();
+        let mut counts = self.0;
+        for (i, rank) in Rank::iter().enumerate() {
+            counts[i] = counts[i]
+                .checked_sub(rhs.0[i])
+                .ok_or(HandArithmeticError::Underflow { rank })?;
+        }
+        self.0 = counts;
+        Ok(())
+    }
+
+    /// Returns this hand with `count` cards of `rank`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HandError::TooManyOfRank`] or [`HandError::TooManyJokers`] if
+    /// `count` exceeds the number of cards of `rank` in a deck.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four });
+    /// assert_eq!(hand.set_count(Rank::Three, 1), Ok(hand!(const { Three, Four })));
+    /// assert_eq!(hand.set_count(Rank::Four, 0), Ok(hand!(const { Three: 3 })));
+    /// assert_eq!(
+    ///     hand.set_count(Rank::Four, 5),
+    ///     Err(HandError::TooManyOfRank { rank: Rank::Four, count: 5 }),
+    /// );
+    /// assert_eq!(
+    ///     hand.set_count(Rank::RedJoker, 2),
+    ///     Err(HandError::TooManyJokers { rank: Rank::RedJoker, count: 2 }),
+    /// );
+    /// ```
+    pub const fn set_count(self, rank: Rank, count: u8) -> Result<Hand, HandError> {
+        if count > rank.max_count() {
+            return Err(match rank {
+                Rank::BlackJoker | Rank::RedJoker => HandError::TooManyJokers { rank, count },
+                _ => HandError::TooManyOfRank { rank, count },
+            });
+        }
+        let mut counts = self.0;
+        counts[rank as usize] = count;
+        Ok(Hand(counts))
+    }
+
+    /// Returns this hand with one more card of `rank`.
+    /// 
+    /// # Errors
+    /// 
+    /// Fails like [`set_count`](Hand::set_count) if this hand already holds every
+    /// card of `rank` in a deck.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { King: 3 }).add_card(Rank::King).unwrap();
+    /// assert_eq!(hand, hand!(const { King: 4 }));
+    /// assert_eq!(
+    ///     hand.add_card(Rank::King),
+    ///     Err(HandError::TooManyOfRank { rank: Rank::King, count: 5 }),
+    /// );
+    /// 
+    /// let hand = Hand::EMPTY.add_card(Rank::BlackJoker).unwrap();
+    /// assert_eq!(hand, hand!(const { BlackJoker }));
+    /// assert_eq!(
+    ///     hand.add_card(Rank::BlackJoker),
+    ///     Err(HandError::TooManyJokers { rank: Rank::BlackJoker, count: 2 }),
+    /// );
+    /// ```
+    pub const fn add_card(self, rank: Rank) -> Result<Hand, HandError> {
+        self.set_count(rank, self.0[rank as usize] + 1)
+    }
+
+    /// Returns this hand with one card of `rank` removed.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HandError::MissingCard`] if this hand holds no card of `rank`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 4, BlackJoker });
+    /// assert_eq!(hand.remove_card(Rank::Three), Ok(hand!(const { Three: 3, BlackJoker })));
+    /// assert_eq!(hand.remove_card(Rank::BlackJoker), Ok(hand!(const { Three: 4 })));
+    /// assert_eq!(
+    ///     hand.remove_card(Rank::RedJoker),
+    ///     Err(HandError::MissingCard { rank: Rank::RedJoker }),
+    /// );
+    /// ```
+    pub const fn remove_card(self, rank: Rank) -> Result<Hand, HandError> {
+        match self.0[rank as usize] {
+            0 => Err(HandError::MissingCard { rank }),
+            count => self.set_count(rank, count - 1),
+        }
+    }
+
+    /// Removes the cards of `rhs` from this hand, clamping each count at zero
+    /// instead of failing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 2, Four, RedJoker });
+    /// assert_eq!(
+    ///     hand.saturating_sub(hand!(const { Three: 3, Four, BlackJoker })),
+    ///     hand!(const { RedJoker }),
+    /// );
+    /// ```
+    pub const fn saturating_sub(self, rhs: Hand) -> Hand {
+        let mut counts = self.0;
         {
             let mut i = 0;
             while i < 15 {
-                sum += self.0[i] as usize;
+                counts[i] = counts[i].saturating_sub(rhs.0[i]);
                 i += 1;
             }
         }
-        sum
+        Hand(counts)
     }
 
-    /// Returns `true` if the hand contains no cards.
+    /// Returns the cards common to this hand and `rhs`, i.e. the minimum of the
+    /// counts of each rank.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use dou_dizhu::*;
     /// 
-    /// assert!(Hand::EMPTY.is_empty());
+    /// let hand = hand!(const { Three: 3, Four, BlackJoker });
+    /// assert_eq!(
+    ///     hand.intersection(hand!(const { Three: 2, Five, BlackJoker, RedJoker })),
+    ///     hand!(const { Three: 2, BlackJoker }),
+    /// );
+    /// assert_eq!(hand.intersection(Hand::EMPTY), Hand::EMPTY);
+    /// 
+    /// // Disjoint hands have nothing in common.
+    /// assert_eq!(hand.intersection(hand!(const { Five: 2, RedJoker })), Hand::EMPTY);
     /// ```
-    pub const fn is_empty(&self) -> bool {
+    pub const fn intersection(self, rhs: Hand) -> Hand {
+        let mut counts = self.0;
         {
             let mut i = 0;
             while i < 15 {
-                if self.0[i] != 0 {
-                    return false;
+                if rhs.0[i] < counts[i] {
+                    counts[i] = rhs.0[i];
                 }
                 i += 1;
             }
         }
-        true
+        Hand(counts)
     }
-}
 
-impl Index<Rank> for Hand {
-    type Output = u8;
+    /// Returns the smallest hand containing both this hand and `rhs`, i.e. the
+    /// maximum of the counts of each rank.
+    /// 
+    /// Unlike addition, this never exceeds the number of cards of a rank in a deck,
+    /// including the single copy of each joker, so it needs no `Option`. For
+    /// disjoint hands it agrees with addition.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four, BlackJoker });
+    /// assert_eq!(
+    ///     hand.union_max(hand!(const { Three: 4, Five, BlackJoker })),
+    ///     hand!(const { Three: 4, Four, Five, BlackJoker }),
+    /// );
+    /// assert_eq!(Hand::FULL_DECK.union_max(hand), Hand::FULL_DECK);
+    /// 
+    /// let disjoint = hand!(const { Five: 2, RedJoker });
+    /// assert_eq!(Some(hand.union_max(disjoint)), hand + disjoint);
+    /// ```
+    pub const fn union_max(self, rhs: Hand) -> Hand {
+        let mut counts = self.0;
+        {
+            let mut i = 0;
+            while i < 15 {
+                if rhs.0[i] > counts[i] {
+                    counts[i] = rhs.0[i];
+                }
+                i += 1;
+            }
+        }
+        Hand(counts)
+    }
 
-    fn index(&self, index: Rank) -> &Self::Output {
-        &self.0[index as usize]
+    /// Returns the cards of a complete deck that are not in this hand.
+    /// 
+    /// This is [`Hand::FULL_DECK`] minus this hand, which cannot fail since every
+    /// hand is a subset of the deck.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 4, Four, RedJoker });
+    /// assert_eq!(hand.complement(), (Hand::FULL_DECK - hand).unwrap());
+    /// assert_eq!(hand.complement().len(), 48);
+    /// assert_eq!(hand.complement().complement(), hand);
+    /// assert_eq!(Hand::EMPTY.complement(), Hand::FULL_DECK);
+    /// ```
+    pub const fn complement(self) -> Hand {
+        Hand::FULL_DECK.saturating_sub(self)
     }
-}
+
+    /// Returns the cards that are neither in this hand nor in any of the `played`
+    /// piles, i.e. the cards still unseen by the holder of this hand.
+    /// 
+    /// Returns `None` if this hand and the played piles together contain more
+    /// cards of some rank than the deck.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 2, Four, RedJoker });
+    /// let played = [
+    ///     &play!(const { Three: 2 }).unwrap(),
+    ///     &play!(const { Five, Six, Seven, Eight, Nine }).unwrap(),
+    /// ];
+    /// let unseen = hand.remaining(&played).unwrap();
+    /// assert_eq!(unseen.len(), 54 - 4 - 2 - 5);
+    /// assert_eq!(unseen[Rank::Three], 0);
+    /// assert_eq!(unseen[Rank::RedJoker], 0);
+    /// assert_eq!(Hand::EMPTY.remaining(&[]), Some(Hand::FULL_DECK));
+    /// 
+    /// let bomb = play!(const { Three: 4 }).unwrap();
+    /// assert_eq!(hand.remaining(&[&bomb]), None);
+    /// ```
+    pub fn remaining(self, played: &[&Guard<Play>]) -> Option<Hand> {
+        let seen = self + played.iter().copied().sum::<Option<Hand>>();
+        Hand::FULL_DECK - seen
+    }
+
+    /// Returns the minimum number of standard plays needed to empty this hand.
+    /// 
+    /// This is the play count of [`min_decomposition`](Hand::min_decomposition),
+    /// without materializing the plays.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(hand!(const { Three: 3, Four }).min_play_count(), 1);
+    /// assert_eq!(hand!(const { Three, Five, Seven }).min_play_count(), 3);
+    /// ```
+    pub fn min_play_count(self) -> usize {
+        let mut lower_bounds = BTreeMap::new();
+        (0..).find(|&limit| self.fits_in(limit, &mut lower_bounds)).unwrap()
+    }
+
+    /// Decomposes this hand into as few standard plays as possible.
+    /// 
+    /// Returns the number of plays together with one optimal decomposition.
+    /// The search deepens the allowed number of plays one at a time and
+    /// remembers, for every hand it has visited, the smallest number of plays
+    /// it is known to need, so the result is always optimal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let chain = hand!(const {
+    ///     Three, Four, Five, Six, Seven, Eight,
+    ///     Nine, Ten, Jack, Queen, King, Ace,
+    /// });
+    /// assert_eq!(chain.min_decomposition().0, 1);
+    /// 
+    /// let hand = hand!(const { Three: 4, Five: 3, Six: 3, Seven, Eight, Two });
+    /// let (count, plays) = hand.min_decomposition();
+    /// assert_eq!(count, 3);
+    /// assert_eq!(plays.len(), 3);
+    /// assert_eq!(
+    ///     plays.iter().fold(Some(Hand::EMPTY), |sum, play| sum + play),
+    ///     Some(hand),
+    /// );
+    /// 
+    /// assert_eq!(Hand::FULL_DECK.min_play_count(), 3);
+    /// ```
+    pub fn min_decomposition(self) -> (usize, Vec<Guard<Play>>) {
+        let mut lower_bounds = BTreeMap::new();
+        let count = (0..).find(|&limit| self.fits_in(limit, &mut lower_bounds)).unwrap();
+        let mut plays = Vec::with_capacity(count);
+        let mut hand = self;
+        for limit in (1..=count).rev() {
+            let play = hand
+                .plays_covering_lowest_rank()
+                .find(|play| unsafe { hand.unchecked_sub(play) }.fits_in(limit - 1, &mut lower_bounds))
+                .unwrap();
+            hand = unsafe { hand.unchecked_sub(&play) };
+            plays.push(play);
+        }
+        (count, plays)
+    }
+
+    /// Decomposes this hand into as few standard plays as possible, keeping as
+    /// many of its bombs and the rocket intact as costs no extra play.
+    /// 
+    /// The number of plays is the one given by
+    /// [`min_decomposition`](Hand::min_decomposition). Among the optimal
+    /// decompositions, one playing as many bombs as possible on their own, the
+    /// rocket counting as a bomb, is preferred, with these coming first. Every
+    /// subset of the bombs is tried, so a bomb is kept even when keeping all of
+    /// them would take an extra play.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// // A chain and a bomb.
+    /// let hand = hand!(const { Five: 4, Six, Seven, Eight, Nine, Ten });
+    /// let plays = hand.decompose().into_iter().map(|x| x.into_inner()).collect::<Vec<_>>();
+    /// assert_eq!(plays, vec![
+    ///     Play::Bomb(Rank::Five),
+    ///     Play::Chain(vec![Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine, Rank::Ten]),
+    /// ]);
+    /// 
+    /// // Breaking the bomb saves plays here.
+    /// let hand = hand!(const { Five: 4, Six, Ten });
+    /// assert_eq!(hand.decompose().len(), 1);
+    /// 
+    /// // Keeping both bombs would leave two solos, but one of them can stay.
+    /// let hand = hand!(const { Three: 4, Four: 4, Five, Six });
+    /// let plays = hand.decompose().into_iter().map(|x| x.into_inner()).collect::<Vec<_>>();
+    /// assert_eq!(plays, vec![
+    ///     Play::Bomb(Rank::Three),
+    ///     Play::FourWithDualSolo { four: Rank::Four, dual_solo: [Rank::Five, Rank::Six] },
+    /// ]);
+    /// 
+    /// assert!(Hand::EMPTY.decompose().is_empty());
+    /// ```
+    pub fn decompose(&self) -> Vec<Guard<Play>> {
+        let mut lower_bounds = BTreeMap::new();
+        let count = (0..).find(|&limit| self.fits_in(limit, &mut lower_bounds)).unwrap();
+        let bombs = self.bombs().collect::<Vec<_>>();
+        // Subsets of the bombs to keep, most bombs first. Each kept bomb is a
+        // play of its own, and the empty subset always works.
+        let mut subsets = (0..1u32 << bombs.len())
+            .filter(|subset| subset.count_ones() as usize <= count)
+            .collect::<Vec<_>>();
+        subsets.sort_by_key(|subset| Reverse(subset.count_ones()));
+        subsets
+            .into_iter()
+            .find_map(|subset| {
+                let kept = (0..bombs.len()).filter(|&i| subset & (1 << i) != 0).map(|i| bombs[i].clone());
+                let rest = kept.clone().fold(*self, |hand, bomb| unsafe { hand.unchecked_sub(&bomb) });
+                rest.fits_in(count - subset.count_ones() as usize, &mut lower_bounds)
+                    .then(|| kept.chain(rest.min_decomposition().1).collect())
+            })
+            .unwrap()
+    }
+
+    /// Returns `true` if this hand can be decomposed into at most `limit` standard plays.
+    /// 
+    /// `lower_bounds` maps hands to a number of plays they are known to need.
+    fn fits_in(self, limit: usize, lower_bounds: &mut BTreeMap<[u8; 15], usize>) -> bool {
+        match limit {
+            _ if self.is_empty() => return true,
+            0 => return false,
+            1 => return self.to_play().is_some(),
+            _ => (),
+        }
+        if lower_bounds.get(&self.0).is_some_and(|&bound| bound > limit) {
+            return false;
+        }
+        let fits = self
+            .plays_covering_lowest_rank()
+            .any(|play| unsafe { self.unchecked_sub(&play) }.fits_in(limit - 1, lower_bounds));
+        if !fits {
+            lower_bounds.insert(self.0, limit + 1);
+        }
+        fits
+    }
+
+    /// Returns an iterator over all standard plays in this hand that contain
+    /// a card of the lowest rank present.
+    /// 
+    /// Every decomposition of a non-empty hand contains at least one such play,
+    /// possibly several, e.g. two solos of that rank. Since the plays of a
+    /// decomposition can be taken in any order, taking one of these first and
+    /// decomposing the rest still reaches every decomposition.
+    fn plays_covering_lowest_rank(self) -> impl Iterator<Item = Guard<Play>> {
+        let lowest = self.0.iter().position(|&count| count != 0).unwrap_or(0);
+        self.all_plays().filter(move |play| play.to_hand().0[lowest] != 0)
+    }
+
+    /// Estimates the probability that leading `play` keeps the initiative,
+    /// i.e. that neither opponent can beat it.
+    /// 
+    /// `unseen` holds the cards not known to the caller, which are assumed to be
+    /// split evenly and uniformly at random between the two opponents. Cards of
+    /// this hand are never dealt to the opponents, even if `unseen` holds them, so
+    /// e.g. the cards not played yet can be passed as is. The estimate is exact
+    /// (`1.0`) when no combination of unseen cards beats `play`; otherwise it is
+    /// obtained by sampling a fixed number of deals with a fixed seed, so the
+    /// result is deterministic.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if this hand does not hold all cards of `play`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three, BlackJoker, RedJoker });
+    /// let unseen = (Hand::FULL_DECK - hand).unwrap();
+    /// 
+    /// let rocket = play!(const { BlackJoker, RedJoker }).unwrap();
+    /// assert_eq!(hand.tempo_value(&rocket, &unseen), 1.0);
+    /// 
+    /// let three = play!(const { Three }).unwrap();
+    /// assert!(hand.tempo_value(&three, &unseen) < 0.01);
+    /// 
+    /// // The cards of this hand are left out of the deals.
+    /// assert_eq!(hand.tempo_value(&three, &Hand::FULL_DECK), hand.tempo_value(&three, &unseen));
+    /// ```
+    pub fn tempo_value(&self, play: &Guard<Play>, unseen: &Hand) -> f64 {
+        const SAMPLES: u32 = 256;
+        assert!(self.contains_play(play), "`play` is not in this hand");
+        let unseen = unseen.saturating_sub(*self);
+        if !unseen.can_beat(play) {
+            return 1.0;
+        }
+        let mut cards = Rank::iter()
+            .flat_map(|rank| iter::repeat_n(rank, unseen[rank] as usize))
+            .collect::<Vec<_>>();
+        let half = cards.len() / 2;
+        // splitmix64
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = move || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        let mut held = 0;
+        for _ in 0..SAMPLES {
+            for i in 0..half {
+                let j = i + (next() % (cards.len() - i) as u64) as usize;
+                cards.swap(i, j);
+            }
+            let mut first = [0u8; 15];
+            for &rank in &cards[..half] {
+                first[rank as usize] += 1;
+            }
+            let first = Hand(first);
+            let second = unsafe { unseen.unchecked_sub(first) };
+            if !first.can_beat(play) && !second.can_beat(play) {
+                held += 1;
+            }
+        }
+        held as f64 / SAMPLES as f64
+    }
+
+    /// Splits the leads available in this hand, other than bombs and the rocket,
+    /// into those likely to keep the initiative and those likely to be beaten.
+    /// 
+    /// A lead is likely to keep the initiative if its [`tempo_value`](Hand::tempo_value)
+    /// against `unseen` is at least one half. Both lists are in the order of
+    /// [`all_plays`](Hand::all_plays).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three, Two: 2 });
+    /// let unseen = hand!(const { Four, Five, Seven: 2, Nine: 2 });
+    /// let (safe, risky) = hand.categorize_leads(&unseen);
+    /// 
+    /// assert!(safe.contains(&play!(const { Two: 2 }).unwrap()));
+    /// assert!(risky.contains(&play!(const { Three }).unwrap()));
+    /// ```
+    pub fn categorize_leads(&self, unseen: &Hand) -> (Vec<Guard<Play>>, Vec<Guard<Play>>) {
+        self.all_plays()
+            .filter(|play| !play.is_bomb_like())
+            .partition(|play| self.tempo_value(play, unseen) >= 0.5)
+    }
+
+    /// Returns `true` if this hand can be played out in a sequence of leads that
+    /// none of the `unseen` cards can beat, except possibly the last one.
+    /// 
+    /// Holding such a hand, a player on lead wins regardless of how `unseen` is split
+    /// between the opponents: every lead but the last keeps the initiative, and
+    /// the last one empties the hand.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Two: 4, Three, BlackJoker, RedJoker });
+    /// let unseen = (Hand::FULL_DECK - hand).unwrap();
+    /// assert!(hand.is_instant_win(&unseen));
+    /// 
+    /// let hand = hand!(const { Three, Four, BlackJoker, RedJoker });
+    /// let unseen = (Hand::FULL_DECK - hand).unwrap();
+    /// assert!(!hand.is_instant_win(&unseen));
+    /// ```
+    pub fn is_instant_win(&self, unseen: &Hand) -> bool {
+        self.closes_out(unseen, true, &mut BTreeSet::new())
+    }
+
+    /// Returns `true` if this hand splits into plays that `unseen` cannot beat,
+    /// plus one arbitrary play if `spare` is set.
+    /// 
+    /// `failures` caches the arguments for which the answer is known to be `false`.
+    fn closes_out(self, unseen: &Hand, spare: bool, failures: &mut BTreeSet<([u8; 15], bool)>) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        if failures.contains(&(self.0, spare)) {
+            return false;
+        }
+        for play in self.plays_covering_lowest_rank() {
+            let unbeatable = !unseen.can_beat(&play);
+            if (unbeatable || spare)
+                && unsafe { self.unchecked_sub(&play) }.closes_out(unseen, spare && unbeatable, failures)
+            {
+                return true;
+            }
+        }
+        failures.insert((self.0, spare));
+        false
+    }
+
+    /// Returns an iterator over all standard plays available in this hand.
+    /// 
+    /// Plays are grouped by kind, in the declaration order of [`PlayKind`]; within a
+    /// kind they come in the same order as from [`plays`](Hand::plays). The iterator
+    /// is lazy, so it can be used for move generation without collecting.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let per_kind: usize = [
+    ///     Solo, Chain, Pair, PairsChain, Trio, Airplane, TrioWithSolo,
+    ///     AirplaneWithSolos, TrioWithPair, AirplaneWithPairs, Bomb,
+    ///     FourWithDualSolo, FourWithDualPair, Rocket,
+    /// ]
+    /// .into_iter()
+    /// .map(|kind| Hand::FULL_DECK.plays(kind).count())
+    /// .sum();
+    /// 
+    /// assert_eq!(Hand::FULL_DECK.all_plays().count(), per_kind);
+    /// assert!(matches!(*hand!(const { Three }).all_plays().next().unwrap(), Play::Solo(Rank::Three)));
+    /// ```
+    pub fn all_plays(self) -> AllPlays {
+        AllPlays { hand: self, kinds: PlayKind::all().iter(), plays: None }
+    }
+
+    /// Returns an iterator over all standard plays in this hand that beat `play`.
+    /// 
+    /// These are the plays of the same kind (and chain length) with a higher rank,
+    /// followed by the bombs and the rocket that beat `play`, as given by
+    /// [`all_bombs_and_rocket`](SearchExt::all_bombs_and_rocket). In particular,
+    /// the plays of each kind come from weakest to strongest.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Four: 2, Nine: 2, Jack: 4, BlackJoker, RedJoker });
+    /// let pair = play!(const { Five: 2 }).unwrap();
+    /// 
+    /// let beats = hand.plays_beating(&pair).map(|x| x.into_inner()).collect::<Vec<_>>();
+    /// assert_eq!(beats, vec![
+    ///     Play::Pair(Rank::Nine),
+    ///     Play::Pair(Rank::Jack),
+    ///     Play::Bomb(Rank::Jack),
+    ///     Play::Rocket,
+    /// ]);
+    /// 
+    /// let bomb = play!(const { Two: 4 }).unwrap();
+    /// assert!(hand.plays_beating(&bomb).eq([play!(const { BlackJoker, RedJoker }).unwrap()]));
+    /// 
+    /// let four = play!(const { Five: 4, Six, Seven }).unwrap();
+    /// assert_eq!(hand.plays_beating(&four).next().unwrap().kind(), FourWithDualSolo);
+    /// ```
+    pub fn plays_beating(self, play: &Guard<Play>) -> impl Iterator<Item = Guard<Play>> {
+        self.plays_over(play)
+    }
+
+    /// Returns an iterator over all standard plays in this hand that can respond to
+    /// `lead`, in the same order as [`plays_beating`](Hand::plays_beating).
+    /// 
+    /// Rather than filtering all plays of the kind of `lead`, the search for them is
+    /// restricted to the chain length of `lead` and to ranks above its
+    /// [`leading_rank`](Guard::leading_rank), using [`PlaySpec::primal_min`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 2, Five: 2, Eight: 4, BlackJoker, RedJoker });
+    /// let pair = play!(const { King: 2 }).unwrap();
+    /// assert!(hand.plays_over(&pair).all(|x| x.is_bomb_like()));
+    /// assert_eq!(hand.plays_over(&pair).count(), 2);
+    /// 
+    /// let chain = play!(const { Three, Four, Five, Six, Seven, Eight, Nine }).unwrap();
+    /// let responses = Hand::FULL_DECK
+    ///     .plays_over(&chain)
+    ///     .filter(|x| x.kind() == Chain)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(responses.len(), 5);
+    /// assert!(responses.iter().all(|x| x.chain_length() == Some(7)));
+    /// ```
+    pub fn plays_over(self, lead: &Guard<Play>) -> impl Iterator<Item = Guard<Play>> {
+        let above = Rank::iter().find(|&rank| rank > lead.leading_rank());
+        let same_kind = match (lead.kind(), above) {
+            (kind, _) if kind.is_bomb_like() => None,
+            (_, None) => None,
+            (kind, Some(rank)) => {
+                let length = lead.chain_length().unwrap_or(1) as u8;
+                let spec = PlaySpec {
+                    primal_count: length..=length,
+                    primal_min: Some(rank),
+                    ..PlaySpec::standard(kind)
+                };
+                Some(self.typed_plays(spec, kind))
+            }
+        };
+        same_kind
+            .into_iter()
+            .flatten()
+            .chain(self.all_bombs_and_rocket().filter(move |x| x > lead))
+    }
+
+    /// Returns an iterator over all standard plays in this hand, each paired with
+    /// the hand remaining after it.
+    /// 
+    /// If `lead` is given, only the plays that can respond to it are yielded, as by
+    /// [`plays_over`](Hand::plays_over); otherwise every play is yielded, as by
+    /// [`all_plays`](Hand::all_plays). Passing is never included. Different plays
+    /// leaving the same hand are yielded separately.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three, Four: 2, Five: 2 });
+    /// let children = hand
+    ///     .children(None)
+    ///     .map(|(play, rest)| (play.into_inner(), rest))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(children, vec![
+    ///     (Play::Solo(Rank::Three), hand!(const { Four: 2, Five: 2 })),
+    ///     (Play::Solo(Rank::Four), hand!(const { Three, Four, Five: 2 })),
+    ///     (Play::Solo(Rank::Five), hand!(const { Three, Four: 2, Five })),
+    ///     (Play::Pair(Rank::Four), hand!(const { Three, Five: 2 })),
+    ///     (Play::Pair(Rank::Five), hand!(const { Three, Four: 2 })),
+    /// ]);
+    /// 
+    /// let lead = play!(const { Four }).unwrap();
+    /// assert!(hand.children(Some(&lead)).map(|(play, _)| play.into_inner()).eq([Play::Solo(Rank::Five)]));
+    /// ```
+    pub fn children(self, lead: Option<&Guard<Play>>) -> impl Iterator<Item = (Guard<Play>, Hand)> {
+        let leads = lead.is_none().then(|| self.all_plays());
+        let responses = lead.map(|lead| self.plays_over(lead));
+        leads
+            .into_iter()
+            .flatten()
+            .chain(responses.into_iter().flatten())
+            .map(move |play| {
+                let rest = unsafe { self.unchecked_sub(&play) };
+                (play, rest)
+            })
+    }
+
+    /// Returns an iterator over all standard plays in this hand that leave `keep`
+    /// available in the remaining hand.
+    /// 
+    /// Plays are yielded in the order of [`all_plays`](Hand::all_plays).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three, Four: 2, Nine, Ten, Jack, Queen, King });
+    /// let chain = play!(const { Nine, Ten, Jack, Queen, King }).unwrap();
+    /// 
+    /// let leads = hand.leads_keeping(&chain).map(|x| x.into_inner()).collect::<Vec<_>>();
+    /// assert_eq!(leads, vec![
+    ///     Play::Solo(Rank::Three),
+    ///     Play::Solo(Rank::Four),
+    ///     Play::Pair(Rank::Four),
+    /// ]);
+    /// ```
+    pub fn leads_keeping(self, keep: &Guard<Play>) -> impl Iterator<Item = Guard<Play>> {
+        self.all_plays()
+            .filter(move |play| (unsafe { self.unchecked_sub(play) } - keep).is_some())
+    }
+
+    /// Returns the weakest play in this hand that beats `play`, or `None` if there is none.
+    /// 
+    /// The lowest-ranked play of the same kind is preferred, falling back to the
+    /// lowest-ranked bomb and then to the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Four, Nine, Queen, Five: 4, BlackJoker, RedJoker });
+    /// 
+    /// let six = play!(const { Six }).unwrap();
+    /// assert!(matches!(*hand.minimum_beat(&six).unwrap(), Play::Solo(Rank::Nine)));
+    /// 
+    /// let pair = play!(const { Six: 2 }).unwrap();
+    /// assert!(matches!(*hand.minimum_beat(&pair).unwrap(), Play::Bomb(Rank::Five)));
+    /// 
+    /// let bomb = play!(const { Six: 4 }).unwrap();
+    /// assert!(matches!(*hand.minimum_beat(&bomb).unwrap(), Play::Rocket));
+    /// 
+    /// assert!(hand!(const { Four, Nine }).minimum_beat(&pair).is_none());
+    /// ```
+    pub fn minimum_beat(&self, play: &Guard<Play>) -> Option<Guard<Play>> {
+        self.plays_beating(play).next()
+    }
+
+    /// Returns the play in this hand that beats `play` with the fewest cards, or
+    /// `None` if there is none.
+    /// 
+    /// Ties are broken by [leading rank](Guard::leading_rank), the lowest first,
+    /// and then by the order of [`plays_beating`](Hand::plays_beating). Unlike
+    /// [`minimum_beat`](Hand::minimum_beat), this may prefer a bomb or the rocket
+    /// to a play of the same kind if it uses fewer cards.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Four, Six, Nine: 3, Jack: 4, Ace: 3 });
+    /// 
+    /// // A trio with a solo takes four cards, as does the bomb of a higher rank.
+    /// let trio = play!(const { Eight: 3, Five }).unwrap();
+    /// assert_eq!(hand.cheapest_beat(&trio).unwrap().to_notation(), "999+4");
+    /// 
+    /// // A plain trio takes three.
+    /// let trio = play!(const { Eight: 3 }).unwrap();
+    /// assert_eq!(hand.cheapest_beat(&trio).unwrap().to_notation(), "999");
+    /// 
+    /// // A trio with a pair takes five cards, more than the bomb.
+    /// let trio = play!(const { Eight: 3, Five: 2 }).unwrap();
+    /// assert_eq!(hand.cheapest_beat(&trio).unwrap().to_notation(), "JJJJ");
+    /// 
+    /// // A chain of six is beaten by the bomb, not by a higher chain.
+    /// let hand = hand!(const { Four, Five, Six, Seven, Eight, Nine, Ten, Queen: 4 });
+    /// let chain = play!(const { Three, Four, Five, Six, Seven, Eight }).unwrap();
+    /// assert_eq!(hand.cheapest_beat(&chain).unwrap().to_notation(), "QQQQ");
+    /// assert_eq!(hand.minimum_beat(&chain).unwrap().to_notation(), "456789");
+    /// 
+    /// // Without the bomb, the lowest higher chain is the cheapest.
+    /// let hand = hand!(const { Four, Five, Six, Seven, Eight, Nine, Ten, Queen: 3 });
+    /// assert_eq!(hand.cheapest_beat(&chain).unwrap().to_notation(), "456789");
+    /// 
+    /// // The rocket takes only two cards.
+    /// let hand = hand!(const { Queen: 4, BlackJoker, RedJoker });
+    /// let bomb = play!(const { Ten: 4 }).unwrap();
+    /// assert_eq!(hand.cheapest_beat(&bomb).unwrap().to_notation(), "BR");
+    /// assert!(hand!(const { Three: 4 }).cheapest_beat(&bomb).is_none());
+    /// ```
+    pub fn cheapest_beat(&self, play: &Guard<Play>) -> Option<Guard<Play>> {
+        self.plays_beating(play).min_by_key(|x| (x.card_count(), x.leading_rank()))
+    }
+
+    /// Returns the lowest-ranked bomb in this hand that beats `target`, or `None`
+    /// if there is none.
+    /// 
+    /// Any bomb beats a play that is neither a bomb nor the rocket. The rocket itself
+    /// is never returned.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Eight: 4, Jack: 4, Queen, BlackJoker, RedJoker });
+    /// 
+    /// let nines = play!(const { Nine: 4 }).unwrap();
+    /// assert!(matches!(*hand.smallest_beating_bomb(&nines).unwrap(), Play::Bomb(Rank::Jack)));
+    /// 
+    /// let pair = play!(const { Two: 2 }).unwrap();
+    /// assert!(matches!(*hand.smallest_beating_bomb(&pair).unwrap(), Play::Bomb(Rank::Eight)));
+    /// 
+    /// let aces = play!(const { Ace: 4 }).unwrap();
+    /// assert!(hand.smallest_beating_bomb(&aces).is_none());
+    /// ```
+    pub fn smallest_beating_bomb(&self, target: &Guard<Play>) -> Option<Guard<Play>> {
+        self.plays(PlayKind::Bomb).find(|bomb| bomb > target)
+    }
+
+    /// Validates playing `cards` from this hand onto `table`, the play to beat,
+    /// or `None` when leading.
+    /// 
+    /// On success, returns the play formed by `cards`. The checks are made in order:
+    /// `cards` must form a standard play, this hand must contain them, and the play
+    /// must beat `table`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three, Five: 2, Nine: 2, Jack: 4 });
+    /// let table = play!(const { Six: 2 }).unwrap();
+    /// 
+    /// let play = hand.validate_move(hand!(const { Nine: 2 }), Some(&table)).unwrap();
+    /// assert!(matches!(*play, Play::Pair(Rank::Nine)));
+    /// assert!(hand.validate_move(hand!(const { Three }), None).is_ok());
+    /// 
+    /// assert_eq!(
+    ///     hand.validate_move(hand!(const { Three, Five }), None).unwrap_err(),
+    ///     MoveError::NotAPlay,
+    /// );
+    /// assert_eq!(
+    ///     hand.validate_move(hand!(const { Ten: 2 }), Some(&table)).unwrap_err(),
+    ///     MoveError::NotInHand { rank: Rank::Ten },
+    /// );
+    /// assert_eq!(
+    ///     hand.validate_move(hand!(const { Five: 2 }), Some(&table)).unwrap_err(),
+    ///     MoveError::TooWeak,
+    /// );
+    /// assert_eq!(
+    ///     hand.validate_move(hand!(const { Three }), Some(&table)).unwrap_err(),
+    ///     MoveError::Incomparable,
+    /// );
+    /// assert!(hand.validate_move(hand!(const { Jack: 4 }), Some(&table)).is_ok());
+    /// ```
+    pub fn validate_move(&self, cards: Hand, table: Option<&Guard<Play>>) -> Result<Guard<Play>, MoveError> {
+        let play = cards.to_play().ok_or(MoveError::NotAPlay)?;
+        if let Some(rank) = Rank::iter().find(|&rank| cards[rank] > self[rank]) {
+            return Err(MoveError::NotInHand { rank });
+        }
+        match table.map(|table| play.partial_cmp(table)) {
+            None | Some(Some(Ordering::Greater)) => Ok(play),
+            Some(Some(_)) => Err(MoveError::TooWeak),
+            Some(None) => Err(MoveError::Incomparable),
+        }
+    }
+
+    /// Returns `true` if this hand holds no more cards of each rank than `other`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four });
+    /// assert!(hand.is_subset_of(&hand!(const { Three: 3, Four: 2 })));
+    /// assert!(hand.is_subset_of(&hand));
+    /// assert!(Hand::EMPTY.is_subset_of(&hand));
+    /// assert!(!hand.is_subset_of(&hand!(const { Three: 2, Four })));
+    /// ```
+    pub const fn is_subset_of(&self, other: &Hand) -> bool {
+        let mut i = 0;
+        while i < 15 {
+            if self.0[i] > other.0[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if this hand holds all cards of `play`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four });
+    /// assert!(hand.contains_play(&play!(const { Three: 3, Four }).unwrap()));
+    /// assert!(!hand.contains_play(&play!(const { Three: 3, Five }).unwrap()));
+    /// assert!(!hand.contains_play(&play!(const { Three: 4 }).unwrap()));
+    /// ```
+    pub fn contains_play(&self, play: &Guard<Play>) -> bool {
+        play.to_hand().is_subset_of(self)
+    }
+
+    /// Returns `true` if some play in this hand beats `play`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let chain = play!(const { Ten, Jack, Queen, King, Ace }).unwrap();
+    /// assert!(!hand!(const { Nine, Ten, Jack, Queen, King }).can_beat(&chain));
+    /// assert!(hand!(const { Three: 4 }).can_beat(&chain));
+    /// assert!(hand!(const { BlackJoker, RedJoker }).can_beat(&play!(const { Two: 4 }).unwrap()));
+    /// ```
+    pub fn can_beat(&self, play: &Guard<Play>) -> bool {
+        self.plays_beating(play).next().is_some()
+    }
+
+    /// Returns the total number of cards in this hand.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(
+    ///     Hand::FULL_DECK.len(),
+    ///     54,
+    /// )
+    /// ```
+    pub const fn len(&self) -> usize {
+        let mut sum = 0;
+        {
+            let mut i = 0;
+            while i < 15 {
+                sum += self.0[i] as usize;
+                i += 1;
+            }
+        }
+        sum
+    }
+
+    /// Returns, for each multiplicity `k` in `0..=4`, the number of distinct ranks
+    /// that appear exactly `k` times in this hand.
+    /// 
+    /// Index 0 counts the ranks absent from the hand, so the entries always sum to 15.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four, Five, Six: 4 });
+    /// assert_eq!(hand.count_by_multiplicity(), [11, 2, 0, 1, 1]);
+    /// ```
+    pub const fn count_by_multiplicity(&self) -> [u8; 5] {
+        let mut result = [0u8; 5];
+        {
+            let mut i = 0;
+            while i < 15 {
+                result[self.0[i] as usize] += 1;
+                i += 1;
+            }
+        }
+        result
+    }
+
+    /// Returns an iterator over the ranks that appear exactly `count` times in this hand,
+    /// in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 3, Four, Five, Six: 4 });
+    /// assert!(hand.ranks_with_count(1).eq([Rank::Four, Rank::Five]));
+    /// ```
+    pub fn ranks_with_count(&self, count: u8) -> impl Iterator<Item = Rank> {
+        let counts = self.0;
+        Rank::iter().filter(move |&rank| counts[rank as usize] == count)
+    }
+
+    /// Returns the number of bombs in this hand, counting the rocket as one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 4, Seven: 3, Ace: 4, BlackJoker, RedJoker });
+    /// assert_eq!(hand.num_bombs(), 3);
+    /// assert_eq!(hand.bombs().count(), hand.num_bombs());
+    /// assert_eq!(hand!(const { Three: 3, RedJoker }).num_bombs(), 0);
+    /// ```
+    pub const fn num_bombs(&self) -> usize {
+        let has_rocket = self.0[Rank::BlackJoker as usize] == 1 && self.0[Rank::RedJoker as usize] == 1;
+        self.count_by_multiplicity()[4] as usize + has_rocket as usize
+    }
+
+    /// Returns an iterator over the bombs in this hand, from weakest to strongest,
+    /// followed by the rocket if this hand has one.
+    /// 
+    /// This is [`all_bombs_and_rocket`](SearchExt::all_bombs_and_rocket).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Three: 4, Seven: 3, Ace: 4, BlackJoker, RedJoker });
+    /// let bombs = hand.bombs().map(|x| x.into_inner()).collect::<Vec<_>>();
+    /// assert_eq!(bombs, vec![Play::Bomb(Rank::Three), Play::Bomb(Rank::Ace), Play::Rocket]);
+    /// ```
+    pub fn bombs(&self) -> impl Iterator<Item = Guard<Play>> {
+        self.all_bombs_and_rocket()
+    }
+
+    /// Returns `true` if the hand contains no cards.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(Hand::EMPTY.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        {
+            let mut i = 0;
+            while i < 15 {
+                if self.0[i] != 0 {
+                    return false;
+                }
+                i += 1;
+            }
+        }
+        true
+    }
+}
+
+/// Draws a count for every rank, from zero up to the number of such cards in a
+/// deck, so every hand produced is valid.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # #[cfg(feature = "arbitrary")]
+/// # {
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use dou_dizhu::*;
+/// 
+/// let bytes = (0..=255).cycle().step_by(7).take(1024).collect::<Vec<u8>>();
+/// let mut u = Unstructured::new(&bytes);
+/// while !u.is_empty() {
+///     let hand = Hand::arbitrary(&mut u).unwrap();
+///     assert_eq!(Hand::try_from(hand.to_array()), Ok(hand));
+/// }
+/// # }
+/// ```
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Hand {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut counts = [0u8; 15];
+        for rank in Rank::iter() {
+            counts[rank as usize] = u.int_in_range(0..=rank.max_count())?;
+        }
+        Ok(Hand(counts))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (15, Some(15))
+    }
+}
+
+/// Hashes the [packed](Hand::to_packed) representation of the hand.
+impl Hash for Hand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_packed().hash(state);
+    }
+}
+
+impl Index<Rank> for Hand {
+    type Output = u8;
+
+    fn index(&self, index: Rank) -> &Self::Output {
+        &self.0[index as usize]
+    }
+}
+
+/// Iterator over the standard plays of one kind in a hand.
+/// 
+/// This `struct` is created by [`Hand::plays`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// fn assert_clone_send<T: Clone + Send>(x: T) -> T {
+///     x
+/// }
+/// 
+/// let mut plays = assert_clone_send(Hand::FULL_DECK.plays(Chain));
+/// plays.next();
+/// assert_eq!(plays.clone().count(), 35);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Plays(PlaysInner);
+
+#[derive(Debug, Clone)]
+enum PlaysInner {
+    Rocket(option::IntoIter<Guard<Play>>),
+    Search(TypedPlays<fn(u8) -> u8>),
+}
+
+impl Iterator for Plays {
+    type Item = Guard<Play>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            PlaysInner::Rocket(plays) => plays.next(),
+            PlaysInner::Search(plays) => plays.next(),
+        }
+    }
+}
+
+/// Iterator over all standard plays in a hand.
+/// 
+/// This `struct` is created by [`Hand::all_plays`].
+#[derive(Debug, Clone)]
+pub struct AllPlays {
+    hand: Hand,
+    kinds: slice::Iter<'static, PlayKind>,
+    plays: Option<Plays>,
+}
+
+impl Iterator for AllPlays {
+    type Item = Guard<Play>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(play) = self.plays.as_mut().and_then(Iterator::next) {
+                return Some(play);
+            }
+            self.plays = Some(self.hand.plays(*self.kinds.next()?));
+        }
+    }
+}
+
+/// Error returned when creating a [`Hand`] from invalid card counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandError {
+    /// More than four cards of the non-joker `rank` were specified.
+    TooManyOfRank { rank: Rank, count: u8 },
+    /// More than one card of the joker `rank` was specified.
+    TooManyJokers { rank: Rank, count: u8 },
+    /// A slice of card counts did not have exactly one entry per rank.
+    InvalidLength { got: usize },
+    /// A [packed](Hand::to_packed) hand had bits set outside of its layout.
+    InvalidPacked { packed: u64 },
+    /// The same `card` was given more than once.
+    DuplicateCard { card: Card },
+    /// A card of `rank` was to be removed from a hand holding none.
+    MissingCard { rank: Rank },
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyOfRank { rank, .. } => write!(f, "more than four `{:?}`s are specified", rank),
+            Self::TooManyJokers { rank, .. } => write!(f, "more than one `{:?}` is specified", rank),
+            Self::InvalidLength { got } => write!(f, "invalid slice length: expected 15, got {}", got),
+            Self::InvalidPacked { packed } => write!(f, "invalid packed hand: bits above bit 44 are set in {:#x}", packed),
+            Self::DuplicateCard { card } => write!(f, "duplicate card: {:?}", card),
+            Self::MissingCard { rank } => write!(f, "no `{:?}` to remove", rank),
+        }
+    }
+}
+
+impl Error for HandError {}
+
+impl From<HandError> for String {
+    fn from(error: HandError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Error returned by [`Hand::validate_move`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    /// The cards do not form a standard play.
+    NotAPlay,
+    /// The hand holds fewer cards of `rank` than the move uses.
+    NotInHand { rank: Rank },
+    /// The play cannot be compared with the play on the table, e.g. because it is
+    /// of another kind.
+    Incomparable,
+    /// The play is not stronger than the play on the table.
+    TooWeak,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAPlay => write!(f, "the cards do not form a standard play"),
+            Self::NotInHand { rank } => write!(f, "not enough `{:?}`s in hand", rank),
+            Self::Incomparable => write!(f, "the play cannot be compared with the play on the table"),
+            Self::TooWeak => write!(f, "the play does not beat the play on the table"),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+/// Error returned by the in-place arithmetic methods of [`Hand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandArithmeticError {
+    /// The result would hold more cards of `rank` than a complete deck does.
+    Overflow { rank: Rank },
+    /// The hand holds fewer cards of `rank` than are being removed.
+    Underflow { rank: Rank },
+}
+
+impl fmt::Display for HandArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow { rank } => write!(f, "too many `{:?}`s after addition", rank),
+            Self::Underflow { rank } => write!(f, "not enough `{:?}`s to subtract", rank),
+        }
+    }
+}
+
+impl Error for HandArithmeticError {}
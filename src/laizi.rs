@@ -0,0 +1,159 @@
+//! Support for the wild card (laizi, 癞子) variant.
+//! 
+//! In this variant, one rank is designated as wild after dealing. Cards of that
+//! rank may stand for themselves or substitute for cards of any other rank except
+//! the jokers. A bomb made with substitutes is *soft* and ranks below every
+//! natural bomb.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use crate::{core::Guard, Hand, Play, Rank};
+
+/// A standard play formed from a hand that may contain wild cards.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, laizi::WildPlay};
+/// 
+/// let natural = WildPlay::from(play!(const { Three: 4 }).unwrap());
+/// assert!(!natural.is_soft());
+/// assert!(natural.play().is_bomb());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WildPlay {
+    play: Guard<Play>,
+    soft: bool,
+}
+
+impl WildPlay {
+    /// Returns the play represented by the cards.
+    pub fn play(&self) -> &Guard<Play> {
+        &self.play
+    }
+
+    /// Returns `true` if at least one wild card substitutes for another rank in this play.
+    pub fn is_soft(&self) -> bool {
+        self.soft
+    }
+
+    /// Returns the play represented by the cards, discarding whether it is soft.
+    pub fn into_play(self) -> Guard<Play> {
+        self.play
+    }
+}
+
+/// Wraps a play made without substitutes.
+impl From<Guard<Play>> for WildPlay {
+    fn from(play: Guard<Play>) -> Self {
+        Self { play, soft: false }
+    }
+}
+
+impl PartialEq for WildPlay {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other).is_some_and(|x| x.is_eq())
+    }
+}
+
+/// Compares plays like [`Guard<Play>`], except that a soft bomb ranks below
+/// every natural bomb.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, laizi::WildPlay};
+/// 
+/// let soft = hand!(const { Five: 3, Nine })
+///     .to_play_with_wild(Rank::Nine)
+///     .find(|x| x.play().is_bomb())
+///     .unwrap();
+/// assert!(soft.is_soft());
+/// assert!(matches!(**soft.play(), Play::Bomb(Rank::Five)));
+/// 
+/// let natural = WildPlay::from(play!(const { Three: 4 }).unwrap());
+/// assert!(natural > soft);
+/// 
+/// let trio = WildPlay::from(play!(const { Two: 3 }).unwrap());
+/// assert!(soft > trio);
+/// ```
+impl PartialOrd for WildPlay {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.play.is_bomb() && other.play.is_bomb() && self.soft != other.soft {
+            return other.soft.partial_cmp(&self.soft);
+        }
+        self.play.partial_cmp(&other.play)
+    }
+}
+
+impl Hand {
+    /// Returns an iterator over all standard plays this hand can represent when
+    /// the cards of rank `wild` are wild.
+    /// 
+    /// Each wild card either stands for itself or substitutes for a card of any
+    /// other rank except the jokers. Plays that can be formed without substitutes
+    /// are reported as natural, even if some other assignment also forms them.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `wild` is a joker.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// // A wild Nine completes a chain.
+    /// let plays = hand!(const { Three, Four, Five, Seven, Nine })
+    ///     .to_play_with_wild(Rank::Nine)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(plays.len(), 1);
+    /// assert!(plays[0].is_soft());
+    /// assert_eq!(
+    ///     plays[0].play().clone().into_inner(),
+    ///     Play::Chain(vec![Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven]),
+    /// );
+    /// 
+    /// // Jokers can never be substituted.
+    /// assert_eq!(hand!(const { Nine, RedJoker }).to_play_with_wild(Rank::Nine).count(), 0);
+    /// ```
+    pub fn to_play_with_wild(self, wild: Rank) -> impl Iterator<Item = WildPlay> {
+        assert!(wild < Rank::BlackJoker, "jokers cannot be wild");
+        let wilds = self.0[wild as usize];
+        let mut natural = self.0;
+        natural[wild as usize] = 0;
+        let mut plays = Vec::<WildPlay>::new();
+        assign_wilds(natural, wild as usize, wilds, 0, false, &mut |counts, soft| {
+            let Some(play) = Hand(counts).to_play() else { return };
+            match plays.iter_mut().find(|x| *x.play == *play) {
+                Some(existing) => existing.soft &= soft,
+                None => plays.push(WildPlay { play, soft }),
+            }
+        });
+        plays.into_iter()
+    }
+}
+
+/// Calls `f` for every way of distributing `wilds` wild cards of rank index `wild`
+/// over the non-joker ranks from `from` upwards, together with whether any of them
+/// substitutes for another rank.
+fn assign_wilds(
+    counts: [u8; 15],
+    wild: usize,
+    wilds: u8,
+    from: usize,
+    soft: bool,
+    f: &mut impl FnMut([u8; 15], bool),
+) {
+    if wilds == 0 {
+        f(counts, soft);
+        return;
+    }
+    for i in from..Rank::BlackJoker as usize {
+        if counts[i] < 4 {
+            let mut counts = counts;
+            counts[i] += 1;
+            assign_wilds(counts, wild, wilds - 1, i, soft || i != wild, f);
+        }
+    }
+}
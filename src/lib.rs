@@ -2,15 +2,44 @@
 //! 
 //! This crate implements Dou Dizhu strictly following the [Pagat rules](https://www.pagat.com/climbing/doudizhu.html),
 //! though it uses different terminology.
+//! 
+//! # Optional features
+//! 
+//! - `arbitrary`: Implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary) for [`Hand`],
+//!   for generating hands in fuzz targets.
+//! - `laizi`: Enables the [`laizi`] module for the wild card variant, in which
+//!   cards of a designated rank may substitute for other ranks.
+//! - `rayon`: Adds parallel iterators over the plays in a hand, such as
+//!   [`Hand::par_plays`], using [`rayon`](https://docs.rs/rayon).
+//! - `std` (default): Links the standard library. Without it, the crate is
+//!   `no_std` and only requires `alloc`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 #[doc(hidden)]
 pub mod __private;
+mod card;
 pub mod core;
+pub mod counter;
+pub mod endgame;
+pub mod eval;
 mod hand;
+#[cfg(feature = "laizi")]
+pub mod laizi;
 mod macros;
+mod notation;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod play;
 mod rank;
+mod render;
+pub mod score;
 
-pub use hand::Hand;
-pub use play::{Play, PlayKind, PlayKind::*};
+pub use card::{Card, Suit};
+pub use hand::{AllPlays, Hand, HandArithmeticError, HandError, MoveError, Plays};
+pub use notation::NotationError;
+pub use play::{ByTotalOrder, Play, PlayError, PlayKind, PlayKind::*};
 pub use rank::Rank;
+pub use render::RenderStyle;
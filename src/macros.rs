@@ -3,7 +3,7 @@
 /// # Examples
 /// 
 /// ```
-/// # fn main() -> Result<(), String> {
+/// # fn main() -> Result<(), dou_dizhu::HandError> {
 /// use dou_dizhu::*;
 /// 
 /// // Compile-time hand
@@ -33,6 +33,64 @@ macro_rules! hand {
     };
 }
 
+/// Macro for constructing [`Hand`](crate::Hand) instances from a list of cards.
+/// 
+/// Each card is written as its rank: `3` to `10`, `J`, `Q`, `K`, `A`, `2`, and
+/// `BJ` and `RJ` for the jokers. The [symbols](crate::Rank::symbol) used by play
+/// notation are accepted as aliases, i.e. `T` for `10`, `B` for `BJ` and `R` for
+/// `RJ`. Repeated cards are tallied, and a rank given more often than it appears
+/// in a deck is rejected at compile time.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// const STRAIGHT: Hand = cards![3, 3, 4, 5, 6, 7, 8, 9, 10, J];
+/// assert_eq!(STRAIGHT, hand!(const { Three: 2, Four, Five, Six, Seven, Eight, Nine, Ten, Jack }));
+/// 
+/// assert_eq!(cards![2, A, 2, BJ, RJ], hand!(const { Ace, Two: 2, BlackJoker, RedJoker }));
+/// assert_eq!(cards![T, 10, B, R], hand!(const { Ten: 2, BlackJoker, RedJoker }));
+/// assert_eq!(cards![], Hand::EMPTY);
+/// ```
+#[macro_export]
+macro_rules! cards {
+    ($($card:tt),* $(,)?) => {
+        const {
+            $crate::__private::hand::from_cards([$(
+                $crate::__private::hand::CardSpec {
+                    rank: $crate::__card_rank!($card),
+                    more_than_four_error: concat!("more than four `", stringify!($card), "`s are specified"),
+                },
+            )*])
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __card_rank {
+    (3) => { $crate::Rank::Three };
+    (4) => { $crate::Rank::Four };
+    (5) => { $crate::Rank::Five };
+    (6) => { $crate::Rank::Six };
+    (7) => { $crate::Rank::Seven };
+    (8) => { $crate::Rank::Eight };
+    (9) => { $crate::Rank::Nine };
+    (10) => { $crate::Rank::Ten };
+    (T) => { $crate::Rank::Ten };
+    (J) => { $crate::Rank::Jack };
+    (Q) => { $crate::Rank::Queen };
+    (K) => { $crate::Rank::King };
+    (A) => { $crate::Rank::Ace };
+    (2) => { $crate::Rank::Two };
+    (BJ) => { $crate::Rank::BlackJoker };
+    (RJ) => { $crate::Rank::RedJoker };
+    (B) => { $crate::Rank::BlackJoker };
+    (R) => { $crate::Rank::RedJoker };
+    ($card:tt) => { compile_error!(concat!("unknown card `", stringify!($card), "`")) };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __hand {
@@ -92,7 +150,10 @@ macro_rules! __const_hand {
 
 /// Macro for constructing [`Play`](crate::Play) instances.
 /// 
-/// The argument syntax for this macro is identical to that of [`hand`].
+/// The argument syntax for this macro is identical to that of [`hand`]. Both
+/// forms return an `Option<Guard<Play>>`, which is `None` if the cards do not form
+/// a standard play, as for [`Hand::to_play`](crate::Hand::to_play). The runtime
+/// form also returns `None` if the counts do not form a valid hand.
 /// 
 /// # Examples
 /// 
@@ -101,6 +162,10 @@ macro_rules! __const_hand {
 /// 
 /// let bomb: Guard<Play> = play!(const { Three: 4 }).unwrap();
 /// assert!(matches!(*bomb, Play::Bomb(Rank::Three)));
+/// 
+/// // Plays with runtime-computed card counts
+/// let kinds = (1..=5).map(|n| play!({ Three: n, Four }).map(|x| x.kind())).collect::<Vec<_>>();
+/// assert_eq!(kinds, vec![None, None, Some(TrioWithSolo), None, None]);
 /// ```
 #[macro_export]
 macro_rules! play {
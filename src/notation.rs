@@ -0,0 +1,131 @@
+use alloc::string::String;
+use core::{error::Error, fmt};
+use crate::{core::Guard, Hand, HandError, Play, Rank};
+
+impl Guard<Play> {
+    /// Returns the canonical notation of this play.
+    /// 
+    /// Each card is written as the [`symbol`](Rank::symbol) of its rank. The primal
+    /// cards come first in ascending order, followed by a `+` and the kicker cards
+    /// in ascending order if the play has kickers. For example, `KKK+5` is a trio
+    /// of Kings with a Five, `34567` a chain and `BR` the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(play!(const { King: 3, Five }).unwrap().to_notation(), "KKK+5");
+    /// assert_eq!(play!(const { Three, Four, Five, Six, Seven }).unwrap().to_notation(), "34567");
+    /// assert_eq!(play!(const { Ten: 4, Two: 2, Three: 2 }).unwrap().to_notation(), "TTTT+3322");
+    /// assert_eq!(play!(const { BlackJoker, RedJoker }).unwrap().to_notation(), "BR");
+    /// ```
+    pub fn to_notation(&self) -> String {
+        let mut notation = String::new();
+        push_cards(&mut notation, self.primal_hand());
+        let kickers = self.kicker_hand();
+        if !kickers.is_empty() {
+            notation.push('+');
+            push_cards(&mut notation, kickers);
+        }
+        notation
+    }
+
+    /// Parses a play from its [notation](Guard::to_notation).
+    /// 
+    /// The cards may be given in any order, and the `+` separating the kickers is
+    /// optional, so `5KKK` parses like `KKK+5`. If present, however, it must
+    /// separate exactly the primal cards from the kickers, so `KK+K5` is rejected.
+    /// The cards are recognized as a play like [`Hand::to_play`] does, so `333444`
+    /// is an airplane, while `33334444`, which is not a standard play, is rejected.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::Guard};
+    /// 
+    /// let play = Guard::<Play>::from_notation("333444").unwrap();
+    /// assert_eq!(play.kind(), Airplane);
+    /// assert_eq!(Guard::<Play>::from_notation("5KKK").unwrap().to_notation(), "KKK+5");
+    /// 
+    /// assert_eq!(Guard::<Play>::from_notation("33334444"), Err(NotationError::NotAPlay));
+    /// assert_eq!(Guard::<Play>::from_notation("KK+K5"), Err(NotationError::MisplacedSeparator));
+    /// assert_eq!(Guard::<Play>::from_notation("K+KK5"), Err(NotationError::MisplacedSeparator));
+    /// assert_eq!(Guard::<Play>::from_notation("KKK+"), Err(NotationError::MisplacedSeparator));
+    /// assert_eq!(Guard::<Play>::from_notation("5+KKK"), Err(NotationError::MisplacedSeparator));
+    /// assert_eq!(Guard::<Play>::from_notation("KKK++5"), Err(NotationError::MisplacedSeparator));
+    /// assert_eq!(
+    ///     Guard::<Play>::from_notation("33X"),
+    ///     Err(NotationError::UnknownSymbol { symbol: 'X' }),
+    /// );
+    /// 
+    /// // Every play round-trips.
+    /// for play in Hand::FULL_DECK.all_plays() {
+    ///     assert_eq!(*Guard::<Play>::from_notation(&play.to_notation()).unwrap(), *play);
+    /// }
+    /// ```
+    pub fn from_notation(notation: &str) -> Result<Guard<Play>, NotationError> {
+        let mut hand = Hand::EMPTY;
+        let mut primal = None;
+        for symbol in notation.chars() {
+            if symbol == '+' {
+                if primal.is_some() {
+                    return Err(NotationError::MisplacedSeparator);
+                }
+                primal = Some(hand);
+                continue;
+            }
+            let rank = Rank::from_symbol(symbol).ok_or(NotationError::UnknownSymbol { symbol })?;
+            hand = hand.add_card(rank).map_err(NotationError::InvalidHand)?;
+        }
+        let play = hand.to_play().ok_or(NotationError::NotAPlay)?;
+        match primal {
+            Some(primal) if primal != play.primal_hand() || play.kicker_hand().is_empty() => {
+                Err(NotationError::MisplacedSeparator)
+            }
+            _ => Ok(play),
+        }
+    }
+}
+
+fn push_cards(notation: &mut String, hand: Hand) {
+    for rank in Rank::iter() {
+        for _ in 0..hand[rank] {
+            notation.push(rank.symbol());
+        }
+    }
+}
+
+/// Error returned by [`Guard::<Play>::from_notation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotationError {
+    /// A character is neither a rank symbol nor the kicker separator `+`.
+    UnknownSymbol { symbol: char },
+    /// The cards do not form a valid hand.
+    InvalidHand(HandError),
+    /// The cards do not form a standard play.
+    NotAPlay,
+    /// The kicker separator `+` does not separate the primal cards from the
+    /// kickers, appears more than once, or the play has no kickers.
+    MisplacedSeparator,
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol { symbol } => write!(f, "unexpected symbol `{}` in play notation", symbol),
+            Self::InvalidHand(error) => write!(f, "invalid cards in play notation: {}", error),
+            Self::NotAPlay => write!(f, "cards in play notation do not form a standard play"),
+            Self::MisplacedSeparator => write!(f, "`+` in play notation does not precede exactly the kickers"),
+        }
+    }
+}
+
+impl Error for NotationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidHand(error) => Some(error),
+            _ => None,
+        }
+    }
+}
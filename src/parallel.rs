@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use rayon::prelude::*;
+use crate::{core::{search::typed_plays_in_window, Guard, PlaySpec, SearchExt}, Hand, Play, PlayKind};
+
+impl Hand {
+    /// Returns a parallel iterator over all standard plays of the given kind in
+    /// this hand.
+    /// 
+    /// The search is split by the primal ranks of the plays. It yields the same
+    /// plays as [`plays`](Hand::plays), though not necessarily in the same order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use rayon::prelude::*;
+    /// 
+    /// for kind in [Chain, AirplaneWithSolos, FourWithDualPair, Rocket] {
+    ///     let mut sequential = Hand::FULL_DECK.plays(kind).map(|x| x.into_inner()).collect::<Vec<_>>();
+    ///     let mut parallel = Hand::FULL_DECK.par_plays(kind).map(|x| x.into_inner()).collect::<Vec<_>>();
+    ///     sequential.sort();
+    ///     parallel.sort();
+    ///     assert_eq!(sequential, parallel);
+    /// }
+    /// ```
+    pub fn par_plays(self, kind: PlayKind) -> impl ParallelIterator<Item = Guard<Play>> {
+        let (windows, rocket) = match kind {
+            PlayKind::Rocket => (Vec::new(), self.plays(kind).collect()),
+            kind => (windows(PlaySpec::standard(kind).primal_count, 0), Vec::new()),
+        };
+        par_windows(self, kind, windows).chain(rocket)
+    }
+
+    /// Returns a parallel iterator over all standard plays in this hand.
+    /// 
+    /// The search is split by the kinds and the primal ranks of the plays. It
+    /// yields the same plays as [`all_plays`](Hand::all_plays), though not
+    /// necessarily in the same order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use rayon::prelude::*;
+    /// 
+    /// let hand = hand!(const {
+    ///     Three: 3, Four: 3, Five: 3, Seven, Nine: 2, Jack, Queen, King, Ace: 2, Two, BlackJoker, RedJoker,
+    /// });
+    /// let mut sequential = hand.all_plays().map(ByTotalOrder).collect::<Vec<_>>();
+    /// let mut parallel = hand.par_all_plays().map(ByTotalOrder).collect::<Vec<_>>();
+    /// sequential.sort();
+    /// parallel.sort();
+    /// assert_eq!(sequential, parallel);
+    /// ```
+    pub fn par_all_plays(self) -> impl ParallelIterator<Item = Guard<Play>> {
+        PlayKind::all().par_iter().flat_map(move |&kind| self.par_plays(kind))
+    }
+
+    /// Returns a parallel iterator over all standard plays in this hand that can
+    /// respond to `lead`.
+    /// 
+    /// The search for plays of the kind of `lead` is split by their primal ranks.
+    /// It yields the same plays as [`plays_over`](Hand::plays_over), though not
+    /// necessarily in the same order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use rayon::prelude::*;
+    /// 
+    /// for lead in [
+    ///     play!(const { Five, Six, Seven, Eight, Nine, Ten }),
+    ///     play!(const { Eight: 3, Nine: 3, Three, Four }),
+    ///     play!(const { Jack: 2 }),
+    ///     play!(const { Ten: 4 }),
+    ///     play!(const { BlackJoker, RedJoker }),
+    /// ] {
+    ///     let lead = lead.unwrap();
+    ///     let mut sequential = Hand::FULL_DECK.plays_over(&lead).map(ByTotalOrder).collect::<Vec<_>>();
+    ///     let mut parallel = Hand::FULL_DECK.par_responses(&lead).map(ByTotalOrder).collect::<Vec<_>>();
+    ///     sequential.sort();
+    ///     parallel.sort();
+    ///     assert_eq!(sequential, parallel);
+    /// }
+    /// ```
+    pub fn par_responses(self, lead: &Guard<Play>) -> impl ParallelIterator<Item = Guard<Play>> {
+        let kind = lead.kind();
+        let windows = match kind {
+            kind if kind.is_bomb_like() => Vec::new(),
+            _ => {
+                let length = lead.chain_length().unwrap_or(1) as u8;
+                windows(length..=length, lead.leading_rank() as u8 + 1)
+            }
+        };
+        let bombs = self.all_bombs_and_rocket().filter(|x| x > lead).collect::<Vec<_>>();
+        par_windows(self, kind, windows).chain(bombs)
+    }
+}
+
+/// Returns the primal windows with the given numbers of primal elements that
+/// start at rank index `floor` or above, as pairs of the number of primal
+/// elements and the starting rank index.
+fn windows(primal_count: RangeInclusive<u8>, floor: u8) -> Vec<(u8, u8)> {
+    primal_count
+        .flat_map(|count| (floor..=15 - count).map(move |start| (count, start)))
+        .collect()
+}
+
+/// Returns a parallel iterator over the standard plays of the given kind in
+/// `hand` with the given primal windows.
+fn par_windows(
+    hand: Hand,
+    kind: PlayKind,
+    windows: Vec<(u8, u8)>,
+) -> impl ParallelIterator<Item = Guard<Play>> {
+    windows
+        .into_par_iter()
+        .flat_map_iter(move |(count, start)| typed_plays_in_window(hand, kind, count, start))
+}
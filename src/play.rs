@@ -1,5 +1,6 @@
-use std::{cmp::Ordering, mem};
-use crate::{core::Guard, Hand, Rank};
+use alloc::vec::Vec;
+use core::{cmp::Ordering, error::Error, fmt, hash::{Hash, Hasher}, iter, mem, slice};
+use crate::{core::{Guard, PlaySpec, SearchExt}, Hand, Rank};
 
 /// A standard Dou Dizhu play.
 /// 
@@ -7,7 +8,7 @@ use crate::{core::Guard, Hand, Rank};
 /// [Pagat rules for Dou Dizhu](https://www.pagat.com/climbing/doudizhu.html).
 /// 
 /// Many of the methods of `Play` are implemented on [`Guard<Play>`].
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Play {
     /// Any single card.
     Solo(Rank),
@@ -73,9 +74,676 @@ impl Play {
             Play::Rocket => PlayKind::Rocket,
         }
     }
+
+    /// Returns `true` if this play is a bomb.
+    /// 
+    /// Only four of a kind played alone is a bomb. A four of a kind carrying kickers
+    /// ([`Play::FourWithDualSolo`] or [`Play::FourWithDualPair`]) is not a bomb and
+    /// only beats plays of its own kind.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(play!(const { Three: 4 }).unwrap().is_bomb());
+    /// assert!(!play!(const { Three: 4, Four, Five }).unwrap().is_bomb());
+    /// assert!(!play!(const { BlackJoker, RedJoker }).unwrap().is_bomb());
+    /// ```
+    pub const fn is_bomb(&self) -> bool {
+        matches!(self, Play::Bomb(_))
+    }
+
+    /// Returns `true` if this play is the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(play!(const { BlackJoker, RedJoker }).unwrap().is_rocket());
+    /// assert!(!play!(const { Two: 4 }).unwrap().is_rocket());
+    /// ```
+    pub const fn is_rocket(&self) -> bool {
+        matches!(self, Play::Rocket)
+    }
+
+    /// Returns `true` if this play is a bomb or the rocket, i.e. if it beats every
+    /// play for which this is `false`.
+    /// 
+    /// See [`PlayKind::is_bomb_like`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(play!(const { Three: 4 }).unwrap().is_bomb_like());
+    /// assert!(play!(const { BlackJoker, RedJoker }).unwrap().is_bomb_like());
+    /// assert!(!play!(const { Three: 4, Four, Five }).unwrap().is_bomb_like());
+    /// ```
+    pub const fn is_bomb_like(&self) -> bool {
+        self.kind().is_bomb_like()
+    }
+
+    /// Returns the factor by which this play multiplies the stake of a deal: 2 for
+    /// [bomb-like](Play::is_bomb_like) plays and 1 otherwise.
+    /// 
+    /// See [`score`](crate::score) for how the stake is computed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(play!(const { Ace: 4 }).unwrap().score_multiplier(), 2);
+    /// assert_eq!(play!(const { BlackJoker, RedJoker }).unwrap().score_multiplier(), 2);
+    /// assert_eq!(play!(const { Ace: 2 }).unwrap().score_multiplier(), 1);
+    /// ```
+    pub const fn score_multiplier(&self) -> u32 {
+        if self.is_bomb_like() { 2 } else { 1 }
+    }
+
+    /// Returns the number of cards in this play.
+    /// 
+    /// The count is computed from the variant alone, without building a [`Hand`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(play!(const { Three: 3, Four: 2 }).unwrap().card_count(), 5);
+    /// assert_eq!(
+    ///     play!(const { Three: 3, Four: 3, Five: 3, Seven, Nine, Jack }).unwrap().card_count(),
+    ///     12,
+    /// );
+    /// 
+    /// for kind in [
+    ///     Solo, Chain, Pair, PairsChain, Trio, Airplane, TrioWithSolo, AirplaneWithSolos,
+    ///     TrioWithPair, AirplaneWithPairs, Bomb, FourWithDualSolo, FourWithDualPair, Rocket,
+    /// ] {
+    ///     for play in Hand::FULL_DECK.plays(kind).step_by(97).take(16) {
+    ///         assert_eq!(play.card_count(), play.to_hand().len());
+    ///     }
+    /// }
+    /// ```
+    pub const fn card_count(&self) -> usize {
+        match self {
+            Play::Solo(_) => 1,
+            Play::Chain(ranks) => ranks.len(),
+            Play::Pair(_) => 2,
+            Play::PairsChain(ranks) => 2 * ranks.len(),
+            Play::Trio(_) => 3,
+            Play::Airplane(ranks) => 3 * ranks.len(),
+            Play::TrioWithSolo { .. } => 4,
+            Play::AirplaneWithSolos { airplane, .. } => 4 * airplane.len(),
+            Play::TrioWithPair { .. } => 5,
+            Play::AirplaneWithPairs { airplane, .. } => 5 * airplane.len(),
+            Play::Bomb(_) => 4,
+            Play::FourWithDualSolo { .. } => 6,
+            Play::FourWithDualPair { .. } => 8,
+            Play::Rocket => 2,
+        }
+    }
+
+    /// Returns `true` if this play beats `other`.
+    /// 
+    /// This applies the same rules as the comparison of [`Guard<Play>`]: a play beats
+    /// another of the same kind (and chain length) with a lower rank, a bomb beats any
+    /// non-bomb play, and the rocket beats everything. Plays that cannot be compared,
+    /// such as chains of different lengths, never beat each other.
+    /// 
+    /// Both plays are assumed to be valid; the result is unspecified otherwise.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let trio = Play::Trio(Rank::Ace);
+    /// let bomb = Play::Bomb(Rank::Three);
+    /// let chain5 = Play::Chain(vec![Rank::Four, Rank::Five, Rank::Six, Rank::Seven, Rank::Eight]);
+    /// let chain6 = Play::Chain(vec![Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven, Rank::Eight]);
+    /// 
+    /// assert!(bomb.beats(&trio));
+    /// assert!(!trio.beats(&bomb));
+    /// assert!(Play::Rocket.beats(&bomb));
+    /// assert!(!bomb.beats(&Play::Rocket));
+    /// assert!(!Play::Rocket.beats(&Play::Rocket));
+    /// assert!(Play::Bomb(Rank::Two).beats(&bomb));
+    /// assert!(!chain5.beats(&chain6));
+    /// assert!(!chain6.beats(&chain5));
+    /// assert!(!trio.beats(&Play::Pair(Rank::Three)));
+    /// ```
+    pub fn beats(&self, other: &Play) -> bool {
+        self.cmp_by_rules(other) == Some(Ordering::Greater)
+    }
+
+    /// Assembles a standard play of the given kind from its primal and kicker ranks,
+    /// or returns `None` if they do not form one.
+    /// 
+    /// The ranks may be given in any order. They are validated against the structure
+    /// of `kind`: the number of primal and kicker ranks, consecutiveness of chains and
+    /// airplanes, and disjointness of primal and kicker ranks. The primal ranks of the
+    /// rocket are both jokers, as returned by [`primal_ranks`](Guard::primal_ranks).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = Play::assemble(AirplaneWithSolos, &[Nine, Eight], &[Three, Ace]).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Eight: 3, Nine: 3, Three, Ace }));
+    /// 
+    /// assert!(Play::assemble(AirplaneWithSolos, &[Eight, Ten], &[Three, Ace]).is_none());
+    /// assert!(Play::assemble(AirplaneWithSolos, &[Eight, Nine], &[Three, Eight]).is_none());
+    /// assert!(Play::assemble(TrioWithSolo, &[Eight, Nine], &[Three]).is_none());
+    /// assert!(Play::assemble(Rocket, &[BlackJoker, RedJoker], &[]).is_some());
+    /// ```
+    pub fn assemble(kind: PlayKind, primal: &[Rank], kicker: &[Rank]) -> Option<Guard<Play>> {
+        let mut primal = primal.to_vec();
+        let mut kicker = kicker.to_vec();
+        primal.sort();
+        kicker.sort();
+        let primal_len_ok = match kind {
+            PlayKind::Rocket => primal == [Rank::BlackJoker, Rank::RedJoker],
+            kind if kind.is_chain_type() => !primal.is_empty(),
+            _ => primal.len() == 1,
+        };
+        let kicker_len = match kind {
+            PlayKind::TrioWithSolo | PlayKind::TrioWithPair => 1,
+            PlayKind::FourWithDualSolo | PlayKind::FourWithDualPair => 2,
+            PlayKind::AirplaneWithSolos | PlayKind::AirplaneWithPairs => primal.len(),
+            _ => 0,
+        };
+        if !primal_len_ok || kicker.len() != kicker_len {
+            return None;
+        }
+        Guard::try_new(Play::from_parts(kind, primal, kicker))
+    }
+
+    /// Checks the structural invariants of this play, returning it as a
+    /// `Guard<Play>` if it is a standard play.
+    /// 
+    /// The ranks of chains and airplanes must be consecutive, ascending and below
+    /// `Two`, and the chains at least as long as required for their kind. Each rank
+    /// must be available in the number of cards the play uses, so jokers can only be
+    /// solos. Kickers must be as many as required, in ascending order, distinct from
+    /// each other and from the primal ranks, and solo kickers must not form the rocket.
+    /// In particular, the two solos of a four with dual solo must have distinct
+    /// ranks, since two cards of the same rank form a pair.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// assert!(Play::Chain(vec![Three, Four, Five, Six, Seven]).validate().is_ok());
+    /// assert_eq!(
+    ///     Play::Chain(vec![Three, Four, Five, Six, Eight]).validate().unwrap_err(),
+    ///     PlayError::NotConsecutive,
+    /// );
+    /// assert_eq!(
+    ///     Play::Airplane(vec![Ace, Two]).validate().unwrap_err(),
+    ///     PlayError::RankNotChainEligible { rank: Two },
+    /// );
+    /// assert_eq!(
+    ///     Play::AirplaneWithSolos { airplane: vec![Three, Four], solos: vec![Five] }.validate().unwrap_err(),
+    ///     PlayError::KickerCountMismatch { expected: 2, got: 1 },
+    /// );
+    /// assert_eq!(
+    ///     Play::AirplaneWithSolos { airplane: vec![Three, Four], solos: vec![BlackJoker, RedJoker] }
+    ///         .validate()
+    ///         .unwrap_err(),
+    ///     PlayError::RocketAsKickers,
+    /// );
+    /// assert_eq!(
+    ///     Play::FourWithDualSolo { four: Nine, dual_solo: [Five, Five] }.validate().unwrap_err(),
+    ///     PlayError::DuplicateKicker { rank: Five },
+    /// );
+    /// assert_eq!(
+    ///     Play::FourWithDualPair { four: Nine, dual_pair: [Six, Five] }.validate().unwrap_err(),
+    ///     PlayError::KickersNotAscending,
+    /// );
+    /// assert_eq!(
+    ///     Play::Pair(RedJoker).validate().unwrap_err(),
+    ///     PlayError::TooManyOfRank { rank: RedJoker, count: 2 },
+    /// );
+    /// ```
+    pub fn validate(self) -> Result<Guard<Play>, PlayError> {
+        let kind = self.kind();
+        if kind == PlayKind::Rocket {
+            return Ok(Guard(self));
+        }
+        let spec = PlaySpec::standard(kind);
+        let primal = self.primal_slice();
+        let kickers = self.kicker_slice();
+        if kind.is_chain_type() {
+            let min = *spec.primal_count.start() as usize;
+            if primal.len() < min {
+                return Err(PlayError::ChainTooShort { len: primal.len(), min });
+            }
+            if let Some(&rank) = primal.iter().find(|&&rank| rank > Rank::Ace) {
+                return Err(PlayError::RankNotChainEligible { rank });
+            }
+            if primal.windows(2).any(|x| x[1] as u8 != x[0] as u8 + 1) {
+                return Err(PlayError::NotConsecutive);
+            }
+        }
+        for &rank in primal {
+            check_count(rank, spec.primal_size)?;
+        }
+        let expected = (spec.kicker_count)(primal.len() as u8) as usize;
+        if kickers.len() != expected {
+            return Err(PlayError::KickerCountMismatch { expected, got: kickers.len() });
+        }
+        for &rank in kickers {
+            check_count(rank, spec.kicker_size)?;
+            if primal.contains(&rank) {
+                return Err(PlayError::KickerOverlapsPrimal { rank });
+            }
+        }
+        for x in kickers.windows(2) {
+            if x[0] == x[1] {
+                return Err(PlayError::DuplicateKicker { rank: x[0] });
+            }
+            if x[0] > x[1] {
+                return Err(PlayError::KickersNotAscending);
+            }
+        }
+        if spec.kicker_size == 1 && kickers.ends_with(&[Rank::BlackJoker, Rank::RedJoker]) {
+            return Err(PlayError::RocketAsKickers);
+        }
+        Ok(Guard(self))
+    }
+
+    /// Returns a solo of the given rank.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Play::solo(Rank::RedJoker).to_hand(), hand!(const { RedJoker }));
+    /// ```
+    pub const fn solo(rank: Rank) -> Guard<Play> {
+        Guard(Play::Solo(rank))
+    }
+
+    /// Returns a pair of the given rank.
+    /// 
+    /// Fails for the jokers, of which a deck has only one each.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Play::pair(Rank::Ace).unwrap().to_hand(), hand!(const { Ace: 2 }));
+    /// assert_eq!(
+    ///     Play::pair(Rank::BlackJoker).unwrap_err(),
+    ///     PlayError::TooManyOfRank { rank: Rank::BlackJoker, count: 2 },
+    /// );
+    /// ```
+    pub const fn pair(rank: Rank) -> Result<Guard<Play>, PlayError> {
+        match check_count(rank, 2) {
+            Ok(()) => Ok(Guard(Play::Pair(rank))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns a trio of the given rank.
+    /// 
+    /// Fails for the jokers, of which a deck has only one each.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Play::trio(Rank::Two).unwrap().to_hand(), hand!(const { Two: 3 }));
+    /// assert!(Play::trio(Rank::RedJoker).is_err());
+    /// ```
+    pub const fn trio(rank: Rank) -> Result<Guard<Play>, PlayError> {
+        match check_count(rank, 3) {
+            Ok(()) => Ok(Guard(Play::Trio(rank))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns a bomb of the given rank.
+    /// 
+    /// Fails for the jokers, of which a deck has only one each.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(Play::bomb(Rank::Three).unwrap().is_bomb());
+    /// assert_eq!(
+    ///     Play::bomb(Rank::RedJoker).unwrap_err(),
+    ///     PlayError::TooManyOfRank { rank: Rank::RedJoker, count: 4 },
+    /// );
+    /// ```
+    pub const fn bomb(rank: Rank) -> Result<Guard<Play>, PlayError> {
+        match check_count(rank, 4) {
+            Ok(()) => Ok(Guard(Play::Bomb(rank))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns a trio of rank `trio` with the solo kicker `solo`.
+    /// 
+    /// Fails if `trio` is a joker or if `solo` has the rank of the trio.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = Play::trio_with_solo(Rank::Ten, Rank::RedJoker).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Ten: 3, RedJoker }));
+    /// assert_eq!(
+    ///     Play::trio_with_solo(Rank::Ten, Rank::Ten).unwrap_err(),
+    ///     PlayError::KickerOverlapsPrimal { rank: Rank::Ten },
+    /// );
+    /// ```
+    pub const fn trio_with_solo(trio: Rank, solo: Rank) -> Result<Guard<Play>, PlayError> {
+        if let Err(e) = check_count(trio, 3) {
+            return Err(e);
+        }
+        if trio as u8 == solo as u8 {
+            return Err(PlayError::KickerOverlapsPrimal { rank: solo });
+        }
+        Ok(Guard(Play::TrioWithSolo { trio, solo }))
+    }
+
+    /// Returns a trio of rank `trio` with the pair kicker `pair`.
+    /// 
+    /// Fails if either rank is a joker or if `pair` has the rank of the trio.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = Play::trio_with_pair(Rank::Ten, Rank::Three).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Ten: 3, Three: 2 }));
+    /// assert!(Play::trio_with_pair(Rank::Ten, Rank::Ten).is_err());
+    /// assert_eq!(
+    ///     Play::trio_with_pair(Rank::Ten, Rank::BlackJoker).unwrap_err(),
+    ///     PlayError::TooManyOfRank { rank: Rank::BlackJoker, count: 2 },
+    /// );
+    /// ```
+    pub const fn trio_with_pair(trio: Rank, pair: Rank) -> Result<Guard<Play>, PlayError> {
+        if let Err(e) = check_count(trio, 3) {
+            return Err(e);
+        }
+        if let Err(e) = check_count(pair, 2) {
+            return Err(e);
+        }
+        if trio as u8 == pair as u8 {
+            return Err(PlayError::KickerOverlapsPrimal { rank: pair });
+        }
+        Ok(Guard(Play::TrioWithPair { trio, pair }))
+    }
+
+    /// Returns a chain of `len` consecutive ranks starting at `start`.
+    /// 
+    /// Fails if `len` is less than 5, if `start` cannot appear in a chain,
+    /// or if the chain would go past `Ace`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let chain = Play::chain(Rank::Ten, 5).unwrap();
+    /// assert_eq!(chain.to_hand(), hand!(const { Ten, Jack, Queen, King, Ace }));
+    /// 
+    /// assert_eq!(Play::chain(Rank::Three, 4).unwrap_err(), PlayError::ChainTooShort { len: 4, min: 5 });
+    /// assert_eq!(Play::chain(Rank::Two, 5).unwrap_err(), PlayError::RankNotChainEligible { rank: Rank::Two });
+    /// assert_eq!(
+    ///     Play::chain(Rank::Jack, 5).unwrap_err(),
+    ///     PlayError::ChainExceedsAce { start: Rank::Jack, len: 5 },
+    /// );
+    /// assert_eq!(
+    ///     Play::chain(Rank::Four, usize::MAX).unwrap_err(),
+    ///     PlayError::ChainExceedsAce { start: Rank::Four, len: usize::MAX },
+    /// );
+    /// ```
+    pub fn chain(start: Rank, len: usize) -> Result<Guard<Play>, PlayError> {
+        chain_ranks(start, len, 5).map(|ranks| Guard(Play::Chain(ranks)))
+    }
+
+    /// Returns a pairs chain of `len` consecutive ranks starting at `start`.
+    /// 
+    /// Fails if `len` is less than 3, if `start` cannot appear in a chain,
+    /// or if the chain would go past `Ace`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let chain = Play::pairs_chain(Rank::Three, 3).unwrap();
+    /// assert_eq!(chain.to_hand(), hand!(const { Three: 2, Four: 2, Five: 2 }));
+    /// assert!(Play::pairs_chain(Rank::Three, 2).is_err());
+    /// assert!(Play::pairs_chain(Rank::Queen, 4).is_err());
+    /// ```
+    pub fn pairs_chain(start: Rank, len: usize) -> Result<Guard<Play>, PlayError> {
+        chain_ranks(start, len, 3).map(|ranks| Guard(Play::PairsChain(ranks)))
+    }
+
+    /// Returns an airplane of `len` consecutive trios starting at `start`.
+    /// 
+    /// Fails if `len` is less than 2, if `start` cannot appear in a chain,
+    /// or if the chain would go past `Ace`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let airplane = Play::airplane(Rank::King, 2).unwrap();
+    /// assert_eq!(airplane.to_hand(), hand!(const { King: 3, Ace: 3 }));
+    /// assert!(Play::airplane(Rank::Ace, 2).is_err());
+    /// assert!(Play::airplane(Rank::BlackJoker, 2).is_err());
+    /// ```
+    pub fn airplane(start: Rank, len: usize) -> Result<Guard<Play>, PlayError> {
+        chain_ranks(start, len, 2).map(|ranks| Guard(Play::Airplane(ranks)))
+    }
+
+    /// Returns an airplane of `airplane_len` consecutive trios starting at `start`,
+    /// with the given solo kickers.
+    /// 
+    /// The kickers may be given in any order. Besides the conditions of
+    /// [`airplane`](Play::airplane), fails unless there is one kicker per trio, the
+    /// kickers are distinct from each other and from the airplane, and they do not
+    /// include both jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = Play::airplane_with_solos(Five, 2, vec![Two, Three]).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Five: 3, Six: 3, Three, Two }));
+    /// 
+    /// assert!(Play::airplane_with_solos(Five, 2, vec![Three]).is_err());
+    /// assert!(Play::airplane_with_solos(Five, 2, vec![Three, Three]).is_err());
+    /// assert!(Play::airplane_with_solos(Five, 2, vec![Three, Six]).is_err());
+    /// assert_eq!(
+    ///     Play::airplane_with_solos(Five, 2, vec![RedJoker, BlackJoker]).unwrap_err(),
+    ///     PlayError::RocketAsKickers,
+    /// );
+    /// ```
+    pub fn airplane_with_solos(start: Rank, airplane_len: usize, mut solos: Vec<Rank>) -> Result<Guard<Play>, PlayError> {
+        let airplane = chain_ranks(start, airplane_len, 2)?;
+        solos.sort();
+        Play::AirplaneWithSolos { airplane, solos }.validate()
+    }
+
+    /// Returns an airplane of `airplane_len` consecutive trios starting at `start`,
+    /// with the given pair kickers.
+    /// 
+    /// The kickers may be given in any order. Besides the conditions of
+    /// [`airplane`](Play::airplane), fails unless there is one kicker per trio, and
+    /// the kickers are distinct from each other and from the airplane, and not jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = Play::airplane_with_pairs(Five, 2, vec![Two, Three]).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Five: 3, Six: 3, Three: 2, Two: 2 }));
+    /// 
+    /// assert!(Play::airplane_with_pairs(Five, 2, vec![Three, Four, Seven]).is_err());
+    /// assert!(Play::airplane_with_pairs(Five, 2, vec![Three, Five]).is_err());
+    /// assert!(Play::airplane_with_pairs(Five, 2, vec![Three, RedJoker]).is_err());
+    /// ```
+    pub fn airplane_with_pairs(start: Rank, airplane_len: usize, mut pairs: Vec<Rank>) -> Result<Guard<Play>, PlayError> {
+        let airplane = chain_ranks(start, airplane_len, 2)?;
+        pairs.sort();
+        Play::AirplaneWithPairs { airplane, pairs }.validate()
+    }
+
+    /// Returns four cards of rank `four` with the two solo kickers `dual_solo`.
+    /// 
+    /// The kickers may be given in either order. Fails if they are equal, if either
+    /// equals `four`, or if they are the two jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = Play::four_with_dual_solo(Nine, [Six, Three]).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Nine: 4, Three, Six }));
+    /// assert!(!play.is_bomb());
+    /// 
+    /// assert_eq!(
+    ///     Play::four_with_dual_solo(Nine, [Six, Six]).unwrap_err(),
+    ///     PlayError::DuplicateKicker { rank: Six },
+    /// );
+    /// assert_eq!(
+    ///     Play::four_with_dual_solo(Nine, [Nine, Six]).unwrap_err(),
+    ///     PlayError::KickerOverlapsPrimal { rank: Nine },
+    /// );
+    /// assert_eq!(
+    ///     Play::four_with_dual_solo(Nine, [RedJoker, BlackJoker]).unwrap_err(),
+    ///     PlayError::RocketAsKickers,
+    /// );
+    /// ```
+    pub fn four_with_dual_solo(four: Rank, mut dual_solo: [Rank; 2]) -> Result<Guard<Play>, PlayError> {
+        dual_solo.sort();
+        Play::FourWithDualSolo { four, dual_solo }.validate()
+    }
+
+    /// Returns four cards of rank `four` with the two pair kickers `dual_pair`.
+    /// 
+    /// The kickers may be given in either order. Fails if they are equal, if either
+    /// equals `four`, or if either is a joker.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = Play::four_with_dual_pair(Nine, [Six, Three]).unwrap();
+    /// assert_eq!(play.to_hand(), hand!(const { Nine: 4, Three: 2, Six: 2 }));
+    /// 
+    /// assert!(Play::four_with_dual_pair(Nine, [Six, Six]).is_err());
+    /// assert!(Play::four_with_dual_pair(Nine, [Nine, Six]).is_err());
+    /// assert!(Play::four_with_dual_pair(Nine, [Six, RedJoker]).is_err());
+    /// ```
+    pub fn four_with_dual_pair(four: Rank, mut dual_pair: [Rank; 2]) -> Result<Guard<Play>, PlayError> {
+        dual_pair.sort();
+        Play::FourWithDualPair { four, dual_pair }.validate()
+    }
+
+    /// Returns the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Play::rocket().to_hand(), hand!(const { BlackJoker, RedJoker }));
+    /// ```
+    pub const fn rocket() -> Guard<Play> {
+        Guard(Play::Rocket)
+    }
+
+    /// Builds a play of the given kind from its primal and kicker ranks.
+    /// 
+    /// The ranks are assumed to be sorted and to form a valid play of `kind`.
+    pub(crate) fn from_parts(kind: PlayKind, primal: Vec<Rank>, kicker: Vec<Rank>) -> Self {
+        match kind {
+            PlayKind::Solo => Play::Solo(primal[0]),
+            PlayKind::Chain => Play::Chain(primal),
+            PlayKind::Pair => Play::Pair(primal[0]),
+            PlayKind::PairsChain => Play::PairsChain(primal),
+            PlayKind::Trio => Play::Trio(primal[0]),
+            PlayKind::Airplane => Play::Airplane(primal),
+            PlayKind::TrioWithSolo => Play::TrioWithSolo { trio: primal[0], solo: kicker[0] },
+            PlayKind::AirplaneWithSolos => Play::AirplaneWithSolos { airplane: primal, solos: kicker },
+            PlayKind::TrioWithPair => Play::TrioWithPair { trio: primal[0], pair: kicker[0] },
+            PlayKind::AirplaneWithPairs => Play::AirplaneWithPairs { airplane: primal, pairs: kicker },
+            PlayKind::Bomb => Play::Bomb(primal[0]),
+            PlayKind::FourWithDualSolo => Play::FourWithDualSolo { four: primal[0], dual_solo: [kicker[0], kicker[1]] },
+            PlayKind::FourWithDualPair => Play::FourWithDualPair { four: primal[0], dual_pair: [kicker[0], kicker[1]] },
+            PlayKind::Rocket => Play::Rocket,
+        }
+    }
 }
 
-impl Guard<Play> {
+impl Guard<Play> {
+    /// Validates `play`, returning it as a `Guard<Play>` if it is a standard play.
+    /// 
+    /// This is the safe alternative to [`Guard::new_unchecked`]. A play is accepted
+    /// only if it is exactly what [`Hand::to_play`] recognizes for its cards, which
+    /// also requires its ranks to be listed in ascending order. See
+    /// [`Play::validate`] for the invariants checked and for the reason of a failure.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::{*, core::Guard};
+    /// use Rank::*;
+    /// 
+    /// let chain = Guard::<Play>::try_new(Play::Chain(vec![Three, Four, Five, Six, Seven]));
+    /// assert!(chain.is_some_and(|x| x.to_hand().len() == 5));
+    /// 
+    /// // Not consecutive
+    /// assert!(Guard::<Play>::try_new(Play::Chain(vec![Three, Four, Five, Six, Eight])).is_none());
+    /// // Too short
+    /// assert!(Guard::<Play>::try_new(Play::Chain(vec![Three, Four, Five, Six])).is_none());
+    /// // Out of order
+    /// assert!(Guard::<Play>::try_new(Play::Chain(vec![Four, Three, Five, Six, Seven])).is_none());
+    /// // Kickers overlapping the airplane
+    /// assert!(Guard::<Play>::try_new(Play::AirplaneWithSolos {
+    ///     airplane: vec![Three, Four],
+    ///     solos: vec![Four, Five],
+    /// }).is_none());
+    /// // Only one card of each joker
+    /// assert!(Guard::<Play>::try_new(Play::Pair(RedJoker)).is_none());
+    /// // Chains cannot contain a Two
+    /// assert!(Guard::<Play>::try_new(Play::PairsChain(vec![King, Ace, Two])).is_none());
+    /// ```
+    pub fn try_new(play: Play) -> Option<Self> {
+        play.validate().ok()
+    }
+
     /// Converts this play into a [`Hand`].
     /// 
     /// # Examples
@@ -144,96 +812,851 @@ impl Guard<Play> {
         }
         Hand(counts)
     }
+
+    /// Returns the rank that determines the strength of this play among plays of
+    /// the same kind.
+    /// 
+    /// This is the rank of a solo, pair, trio or bomb, the lowest rank of a chain
+    /// or airplane, and the rank of the trio or four-of-a-kind carrying kickers.
+    /// The rocket, which has no such rank, returns [`Rank::RedJoker`] as a sentinel.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// for (play, rank) in [
+    ///     (play!(const { Nine }), Rank::Nine),
+    ///     (play!(const { Five, Six, Seven, Eight, Nine }), Rank::Five),
+    ///     (play!(const { Two: 2 }), Rank::Two),
+    ///     (play!(const { Four: 2, Five: 2, Six: 2 }), Rank::Four),
+    ///     (play!(const { King: 3 }), Rank::King),
+    ///     (play!(const { Seven: 3, Eight: 3 }), Rank::Seven),
+    ///     (play!(const { Ten: 3, Three }), Rank::Ten),
+    ///     (play!(const { Jack: 3, Queen: 3, Three, Two }), Rank::Jack),
+    ///     (play!(const { Three: 3, Ace: 2 }), Rank::Three),
+    ///     (play!(const { Six: 3, Seven: 3, Ace: 2, Two: 2 }), Rank::Six),
+    ///     (play!(const { Queen: 4 }), Rank::Queen),
+    ///     (play!(const { Eight: 4, Three, RedJoker }), Rank::Eight),
+    ///     (play!(const { Three: 4, Four: 2, Five: 2 }), Rank::Three),
+    ///     (play!(const { BlackJoker, RedJoker }), Rank::RedJoker),
+    /// ] {
+    ///     assert_eq!(play.unwrap().leading_rank(), rank);
+    /// }
+    /// ```
+    pub const fn leading_rank(&self) -> Rank {
+        match self.0 {
+            Play::Solo(rank)
+            | Play::Pair(rank)
+            | Play::Trio(rank)
+            | Play::Bomb(rank)
+            | Play::TrioWithSolo { trio: rank, .. }
+            | Play::TrioWithPair { trio: rank, .. }
+            | Play::FourWithDualSolo { four: rank, .. }
+            | Play::FourWithDualPair { four: rank, .. } => rank,
+            Play::Chain(ref ranks)
+            | Play::PairsChain(ref ranks)
+            | Play::Airplane(ref ranks)
+            | Play::AirplaneWithSolos { airplane: ref ranks, .. }
+            | Play::AirplaneWithPairs { airplane: ref ranks, .. } => ranks.as_slice()[0],
+            Play::Rocket => Rank::RedJoker,
+        }
+    }
+
+    /// Returns whether this play beats `other`, or `None` if the two plays cannot
+    /// be compared, such as plays of different non-bomb kinds.
+    /// 
+    /// This returns `Some(false)` if `other` beats this play or has equal strength.
+    /// Unlike [`Play::beats`], it tells incomparable plays apart from plays that
+    /// lose.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let pair = play!(const { Ace: 2 }).unwrap();
+    /// let bomb = play!(const { Three: 4 }).unwrap();
+    /// let trio = play!(const { Three: 3 }).unwrap();
+    /// 
+    /// assert_eq!(bomb.try_beats(&pair), Some(true));
+    /// assert_eq!(pair.try_beats(&bomb), Some(false));
+    /// assert_eq!(pair.try_beats(&pair), Some(false));
+    /// assert_eq!(pair.try_beats(&trio), None);
+    /// 
+    /// // `Play::beats` is still reachable and does not tell the two cases apart.
+    /// assert!(!pair.beats(&bomb));
+    /// assert!(!pair.beats(&trio));
+    /// ```
+    pub fn try_beats(&self, other: &Guard<Play>) -> Option<bool> {
+        self.partial_cmp(other).map(Ordering::is_gt)
+    }
+
+    /// Returns `false` if no play can beat this one, which is only the case for the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(play!(const { Two: 4 }).unwrap().can_be_beaten());
+    /// assert!(!play!(const { BlackJoker, RedJoker }).unwrap().can_be_beaten());
+    /// ```
+    pub const fn can_be_beaten(&self) -> bool {
+        !self.0.is_rocket()
+    }
+
+    /// Returns the number of consecutive primal ranks if this is a chain-type play,
+    /// or `None` otherwise.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// for (play, length) in [
+    ///     (play!(const { Nine }), None),
+    ///     (play!(const { Five, Six, Seven, Eight, Nine }), Some(5)),
+    ///     (play!(const { Two: 2 }), None),
+    ///     (play!(const { Four: 2, Five: 2, Six: 2 }), Some(3)),
+    ///     (play!(const { King: 3 }), None),
+    ///     (play!(const { Seven: 3, Eight: 3 }), Some(2)),
+    ///     (play!(const { Ten: 3, Three }), None),
+    ///     (play!(const { Jack: 3, Queen: 3, King: 3, Three, Four, Two }), Some(3)),
+    ///     (play!(const { Three: 3, Ace: 2 }), None),
+    ///     (play!(const { Six: 3, Seven: 3, Ace: 2, Two: 2 }), Some(2)),
+    ///     (play!(const { Queen: 4 }), None),
+    ///     (play!(const { Eight: 4, Three, RedJoker }), None),
+    ///     (play!(const { Three: 4, Four: 2, Five: 2 }), None),
+    ///     (play!(const { BlackJoker, RedJoker }), None),
+    /// ] {
+    ///     let play = play.unwrap();
+    ///     assert_eq!(play.chain_length(), length);
+    ///     assert_eq!(play.is_chain_type(), length.is_some());
+    /// }
+    /// ```
+    pub fn chain_length(&self) -> Option<usize> {
+        self.is_chain_type().then(|| self.0.primal_slice().len())
+    }
+
+    /// Returns the rank that determines the strength of this play among plays of
+    /// the same kind, or `None` for the rocket.
+    /// 
+    /// This is [`leading_rank`](Self::leading_rank) without the sentinel for the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(play!(const { Ten: 3, Three }).unwrap().primal_rank(), Some(Rank::Ten));
+    /// assert_eq!(play!(const { BlackJoker, RedJoker }).unwrap().primal_rank(), None);
+    /// ```
+    pub const fn primal_rank(&self) -> Option<Rank> {
+        if self.0.is_rocket() {
+            None
+        } else {
+            Some(self.leading_rank())
+        }
+    }
+
+    /// Returns the length of the chain part of this play, which is 1 for plays
+    /// that are not chain-type.
+    /// 
+    /// This is [`chain_length`](Self::chain_length) with non-chain plays counted as
+    /// chains of length 1. Two plays of the same kind are comparable if and only if
+    /// their chain lengths are equal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(play!(const { Four: 2, Five: 2, Six: 2 }).unwrap().chain_len(), 3);
+    /// assert_eq!(play!(const { Four: 2 }).unwrap().chain_len(), 1);
+    /// ```
+    pub fn chain_len(&self) -> usize {
+        self.chain_length().unwrap_or(1)
+    }
+
+    /// Returns the kicker cards of this play in ascending order, listing each rank
+    /// as many times as it is played, or an empty `Vec` if this play carries no kickers.
+    /// 
+    /// See [`kicker_ranks`](Self::kicker_ranks) for the distinct kicker ranks.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = play!(const { Three: 4, Four: 2, Five: 2 }).unwrap();
+    /// assert_eq!(play.kickers(), vec![Four, Four, Five, Five]);
+    /// 
+    /// let play = play!(const { Jack: 3, Queen: 3, Three, Two }).unwrap();
+    /// assert_eq!(play.kickers(), vec![Three, Two]);
+    /// 
+    /// assert!(play!(const { Jack: 3 }).unwrap().kickers().is_empty());
+    /// ```
+    pub fn kickers(&self) -> Vec<Rank> {
+        let multiplicity = match self.kind() {
+            PlayKind::TrioWithPair | PlayKind::AirplaneWithPairs | PlayKind::FourWithDualPair => 2,
+            _ => 1,
+        };
+        self.0
+            .kicker_slice()
+            .iter()
+            .flat_map(|&rank| iter::repeat_n(rank, multiplicity))
+            .collect()
+    }
+
+    /// Returns `true` if this is a chain-type play.
+    /// 
+    /// See [`PlayKind::is_chain_type`].
+    pub const fn is_chain_type(&self) -> bool {
+        self.0.kind().is_chain_type()
+    }
+
+    /// Returns the ranks used by this play together with their counts, in ascending
+    /// order of rank.
+    /// 
+    /// Two plays use exactly the same cards if and only if their rank multisets are
+    /// equal. This is finer than the comparison of `Guard<Play>`, under which plays
+    /// that differ only in their kickers are equal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let a = play!(const { King: 3, Three }).unwrap();
+    /// let b = play!(const { King: 3, Four }).unwrap();
+    /// 
+    /// assert!(a == b);
+    /// assert_eq!(a.rank_multiset(), vec![(Rank::Three, 1), (Rank::King, 3)]);
+    /// assert_ne!(a.rank_multiset(), b.rank_multiset());
+    /// ```
+    pub fn rank_multiset(&self) -> Vec<(Rank, u8)> {
+        let hand = self.to_hand();
+        Rank::iter()
+            .filter(|&rank| hand[rank] != 0)
+            .map(|rank| (rank, hand[rank]))
+            .collect()
+    }
+
+    /// Returns the primal ranks of this play, i.e. the ranks of the cards that are
+    /// not kickers, in ascending order.
+    /// 
+    /// For the rocket, these are both jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = play!(const { Jack: 3, Queen: 3, Three, Two }).unwrap();
+    /// assert_eq!(play.primal_ranks(), vec![Rank::Jack, Rank::Queen]);
+    /// 
+    /// let play = play!(const { BlackJoker, RedJoker }).unwrap();
+    /// assert_eq!(play.primal_ranks(), vec![Rank::BlackJoker, Rank::RedJoker]);
+    /// ```
+    pub fn primal_ranks(&self) -> Vec<Rank> {
+        self.0.primal_slice().to_vec()
+    }
+
+    /// Returns the kicker ranks of this play in ascending order, or an empty `Vec`
+    /// if this play carries no kickers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = play!(const { Three: 4, Four: 2, Five: 2 }).unwrap();
+    /// assert_eq!(play.kicker_ranks(), vec![Rank::Four, Rank::Five]);
+    /// 
+    /// let play = play!(const { Four: 2, Five: 2, Six: 2 }).unwrap();
+    /// assert!(play.kicker_ranks().is_empty());
+    /// ```
+    pub fn kicker_ranks(&self) -> Vec<Rank> {
+        self.0.kicker_slice().to_vec()
+    }
+
+    /// Splits this play into its units of cards of a single rank, primal units first,
+    /// each in ascending order of rank.
+    /// 
+    /// Each unit is described by the kind of play it would form on its own: a
+    /// [`Solo`](PlayKind::Solo), [`Pair`](PlayKind::Pair), [`Trio`](PlayKind::Trio)
+    /// or, for four of a kind, [`Bomb`](PlayKind::Bomb). The rocket splits into its
+    /// two jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let play = play!(const { King: 3, Three: 2 }).unwrap();
+    /// assert_eq!(play.units(), vec![(Trio, King), (Pair, Three)]);
+    /// 
+    /// let play = play!(const { Six: 3, Seven: 3, Ace: 2, Two: 2 }).unwrap();
+    /// assert_eq!(play.units(), vec![(Trio, Six), (Trio, Seven), (Pair, Ace), (Pair, Two)]);
+    /// 
+    /// let play = play!(const { Eight: 4, Three, Five }).unwrap();
+    /// assert_eq!(play.units(), vec![(Bomb, Eight), (Solo, Three), (Solo, Five)]);
+    /// 
+    /// let play = play!(const { BlackJoker, RedJoker }).unwrap();
+    /// assert_eq!(play.units(), vec![(Solo, BlackJoker), (Solo, RedJoker)]);
+    /// ```
+    pub fn units(&self) -> Vec<(PlayKind, Rank)> {
+        let hand = self.to_hand();
+        self.0
+            .primal_slice()
+            .iter()
+            .chain(self.0.kicker_slice())
+            .map(|&rank| {
+                let kind = match hand[rank] {
+                    1 => PlayKind::Solo,
+                    2 => PlayKind::Pair,
+                    3 => PlayKind::Trio,
+                    _ => PlayKind::Bomb,
+                };
+                (kind, rank)
+            })
+            .collect()
+    }
+
+    /// Returns the primal cards of this play as a [`Hand`].
+    /// 
+    /// Together with [`kicker_hand`](Self::kicker_hand), this splits the cards of
+    /// the play, whose number is given by [`card_count`](Play::card_count).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = play!(const { Six: 3, Seven: 3, Ace: 2, Two: 2 }).unwrap();
+    /// assert_eq!(play.primal_hand(), hand!(const { Six: 3, Seven: 3 }));
+    /// assert_eq!(play.kicker_hand(), hand!(const { Ace: 2, Two: 2 }));
+    /// assert_eq!(play.primal_hand() + play.kicker_hand(), Some(play.to_hand()));
+    /// assert_eq!(play.card_count(), 10);
+    /// ```
+    pub fn primal_hand(&self) -> Hand {
+        self.cards_of(self.primal_ranks())
+    }
+
+    /// Returns the kicker cards of this play as a [`Hand`], which is empty if this
+    /// play carries no kickers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = play!(const { Eight: 4, Three, RedJoker }).unwrap();
+    /// assert_eq!(play.kicker_hand(), hand!(const { Three, RedJoker }));
+    /// assert!(play!(const { Eight: 4 }).unwrap().kicker_hand().is_empty());
+    /// ```
+    pub fn kicker_hand(&self) -> Hand {
+        self.cards_of(self.kicker_ranks())
+    }
+
+    /// Returns the weakest standard play of the same kind and chain length that
+    /// beats this play, regardless of which cards are available.
+    /// 
+    /// Only the primal ranks advance, and the kickers are kept if they are still
+    /// valid with the new primal ranks. Otherwise, e.g. if the primal ranks would
+    /// overlap them, the lowest valid kickers are chosen instead. Returns `None` if
+    /// there is no stronger play of the same kind and length, in particular for
+    /// the rocket.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// use Rank::*;
+    /// 
+    /// let next = |play: core::Guard<Play>| play.next_stronger().map(|x| x.into_inner());
+    /// 
+    /// assert_eq!(next(Play::solo(Three)), Some(Play::Solo(Four)));
+    /// assert_eq!(next(Play::solo(BlackJoker)), Some(Play::Solo(RedJoker)));
+    /// assert_eq!(next(Play::bomb(King).unwrap()), Some(Play::Bomb(Ace)));
+    /// assert_eq!(next(Play::bomb(Ace).unwrap()), Some(Play::Bomb(Two)));
+    /// assert_eq!(next(Play::bomb(Two).unwrap()), None);
+    /// assert_eq!(next(Play::rocket()), None);
+    /// 
+    /// assert_eq!(
+    ///     next(Play::chain(Three, 5).unwrap()),
+    ///     Some(Play::Chain(vec![Four, Five, Six, Seven, Eight])),
+    /// );
+    /// assert_eq!(next(Play::chain(Ten, 5).unwrap()), None);
+    /// 
+    /// // The kicker is kept where possible...
+    /// assert_eq!(
+    ///     next(Play::trio_with_solo(Three, Seven).unwrap()),
+    ///     Some(Play::TrioWithSolo { trio: Four, solo: Seven }),
+    /// );
+    /// // ...and replaced by the lowest one otherwise.
+    /// assert_eq!(
+    ///     next(Play::trio_with_solo(Three, Four).unwrap()),
+    ///     Some(Play::TrioWithSolo { trio: Four, solo: Three }),
+    /// );
+    /// assert_eq!(
+    ///     next(Play::trio_with_solo(Ace, Two).unwrap()),
+    ///     Some(Play::TrioWithSolo { trio: Two, solo: Three }),
+    /// );
+    /// assert_eq!(
+    ///     next(Play::four_with_dual_solo(Ace, [Two, Three]).unwrap()),
+    ///     Some(Play::FourWithDualSolo { four: Two, dual_solo: [Three, Four] }),
+    /// );
+    /// 
+    /// // Every play of the full deck but the strongest of its kind has a successor,
+    /// // which beats it.
+    /// for play in Hand::FULL_DECK.all_plays() {
+    ///     match play.next_stronger() {
+    ///         Some(next) => assert!(next > play),
+    ///         None => assert!(Hand::FULL_DECK.plays_over(&play).all(|x| x.kind() != play.kind())),
+    ///     }
+    /// }
+    /// ```
+    pub fn next_stronger(&self) -> Option<Guard<Play>> {
+        if self.is_rocket() {
+            return None;
+        }
+        let kind = self.kind();
+        let primal = self.0.primal_slice();
+        let kicker = self.0.kicker_slice();
+        let length = primal.len() as u8;
+        (1..15).find_map(|step| {
+            let primal = primal
+                .iter()
+                .map(|&rank| Rank::iter().nth(rank as usize + step))
+                .collect::<Option<Vec<_>>>()?;
+            let start = primal[0];
+            Guard::try_new(Play::from_parts(kind, primal, kicker.to_vec())).or_else(|| {
+                let spec = PlaySpec {
+                    primal_count: length..=length,
+                    primal_min: Some(start),
+                    ..PlaySpec::standard(kind)
+                };
+                Hand::FULL_DECK
+                    .typed_plays(spec, kind)
+                    .next()
+                    .filter(|play| play.0.primal_slice()[0] == start)
+            })
+        })
+    }
+
+    /// Returns the cards of this play of the given ranks.
+    fn cards_of(&self, ranks: Vec<Rank>) -> Hand {
+        let hand = self.to_hand();
+        let mut counts = [0u8; 15];
+        for rank in ranks {
+            counts[rank as usize] = hand[rank];
+        }
+        Hand(counts)
+    }
+}
+
+impl From<&Guard<Play>> for Hand {
+    fn from(play: &Guard<Play>) -> Self {
+        play.to_hand()
+    }
+}
+
+impl PartialEq for Guard<Play> {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other).is_some_and(|x| x.is_eq())
+    }
+}
+
+/// Plays are equal if they have equal strength, which is an equivalence relation.
+impl Eq for Guard<Play> {}
+
+/// Compares the underlying plays structurally, i.e. whether they are the same
+/// play, with the same kickers.
+/// 
+/// This differs from comparing two `Guard<Play>`s, which compares them by
+/// strength, so that plays differing only in their kickers are equal.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// let with_five = play!(const { King: 3, Five }).unwrap();
+/// let with_six = play!(const { King: 3, Six }).unwrap();
+/// 
+/// // Equal strength, but not the same play.
+/// assert_eq!(with_five, with_six);
+/// assert_ne!(with_five, *with_six);
+/// assert_eq!(with_five, Play::TrioWithSolo { trio: Rank::King, solo: Rank::Five });
+/// assert_eq!(Play::TrioWithSolo { trio: Rank::King, solo: Rank::Six }, with_six);
+/// 
+/// assert_eq!(play!(const { BlackJoker, RedJoker }).unwrap(), Play::Rocket);
+/// ```
+impl PartialEq<Play> for Guard<Play> {
+    fn eq(&self, other: &Play) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Compares the plays structurally, as the reverse implementation does.
+impl PartialEq<Guard<Play>> for Play {
+    fn eq(&self, other: &Guard<Play>) -> bool {
+        *self == other.0
+    }
+}
+
+/// `Guard<Play>` does not implement [`Borrow<Play>`](core::borrow::Borrow), since it
+/// compares and hashes by strength rather than structurally like `Play`.
+impl AsRef<Play> for Guard<Play> {
+    fn as_ref(&self) -> &Play {
+        &self.0
+    }
+}
+
+/// Compares plays by the rules of the game.
+/// 
+/// Plays of different kinds, other than bombs and the rocket, are incomparable.
+/// 
+/// **Chain-like plays of different lengths are incomparable too**, even if they
+/// are of the same kind: `partial_cmp` returns `None`, and neither `<`, `>` nor
+/// `==` holds. Chain-like plays of the same length are compared by their primal
+/// ranks, which for valid chains amounts to comparing their lowest ranks.
+/// 
+/// Bombs and the rocket, the plays for which [`is_bomb_like`](Play::is_bomb_like)
+/// is `true`, beat every play for which it is `false`.
+/// 
+/// This is only a partial order. For a total order on the underlying plays,
+/// see the [`Ord`] implementation of [`Play`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// let (bomb_like, others): (Vec<_>, Vec<_>) =
+///     Hand::FULL_DECK.all_plays().partition(|x| x.is_bomb_like());
+/// assert_eq!(bomb_like.len(), 14);
+/// for strong in &bomb_like {
+///     assert!(others.iter().all(|weak| strong > weak && weak < strong));
+/// }
+/// 
+/// let low = play!(const { Three, Four, Five, Six, Seven }).unwrap();
+/// let high = play!(const { Eight, Nine, Ten, Jack, Queen }).unwrap();
+/// let long = play!(const { Three, Four, Five, Six, Seven, Eight }).unwrap();
+/// 
+/// assert!(high > low);
+/// assert!(low < high);
+/// assert_eq!(low.partial_cmp(&long), None);
+/// assert!(!(long > low) && !(long < low) && long != low);
+/// 
+/// let low = play!(const { Three: 3, Four: 3, Ace, Two }).unwrap();
+/// let high = play!(const { Four: 3, Five: 3, Three, Six }).unwrap();
+/// assert!(high > low);
+/// 
+/// // Even malformed chains compare consistently.
+/// use dou_dizhu::core::Guard;
+/// use Rank::*;
+/// let gap = unsafe { Guard::new_unchecked(Play::Chain(vec![Three, Four, Five, Six, Eight])) };
+/// let chain = play!(const { Three, Four, Five, Six, Seven }).unwrap();
+/// assert!(gap != chain);
+/// assert!(gap > chain);
+/// ```
+impl PartialOrd for Guard<Play> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.cmp_by_rules(&other.0)
+    }
+}
+
+/// Hashes the kind, leading rank and length of a play, which is consistent with
+/// comparison by strength: plays differing only in their kickers hash the same.
+/// 
+/// Hashing the underlying [`Play`] structurally would break this, since such
+/// plays compare equal as `Guard<Play>` but not as `Play`. To key on the exact
+/// cards, use the `Play` itself, e.g. via [`into_inner`](Guard::into_inner).
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::collections::HashSet;
+/// use dou_dizhu::*;
+/// 
+/// let trios = Hand::FULL_DECK.plays(TrioWithSolo).collect::<HashSet<_>>();
+/// assert_eq!(trios.len(), 13);
+/// 
+/// let exact = Hand::FULL_DECK
+///     .plays(TrioWithSolo)
+///     .map(|x| x.into_inner())
+///     .collect::<HashSet<_>>();
+/// assert_eq!(exact.len(), 13 * 14);
+/// ```
+impl Hash for Guard<Play> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.kind().hash(state);
+        self.leading_rank().hash(state);
+        self.0.primal_slice().len().hash(state);
+    }
+}
+
+/// Orders plays by kind in the order of [`PlayKind`], then by primal ranks,
+/// i.e. by leading rank and then by chain length, and finally by kicker ranks.
+/// 
+/// This order has nothing to do with which play beats which; it only makes plays
+/// usable in sorted collections. For comparison by the rules of the game, see
+/// [`Play::beats`] and the [`PartialOrd`] implementation of [`Guard<Play>`].
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::collections::BTreeSet;
+/// use dou_dizhu::*;
+/// 
+/// let plays = [
+///     play!(const { Four, Five, Six, Seven, Eight, Nine }),
+///     play!(const { Three: 3, Four }),
+///     play!(const { Four, Five, Six, Seven, Eight }),
+///     play!(const { Three: 3, Five }),
+///     play!(const { Four, Five, Six, Seven, Eight }),
+/// ]
+/// .map(|x| x.unwrap().into_inner());
+/// 
+/// let sorted = plays.iter().cloned().collect::<BTreeSet<_>>();
+/// assert!(sorted.into_iter().eq([
+///     plays[2].clone(),
+///     plays[0].clone(),
+///     plays[1].clone(),
+///     plays[3].clone(),
+/// ]));
+/// ```
+impl Ord for Play {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.kind()
+            .cmp(&other.kind())
+            .then_with(|| self.primal_slice().cmp(other.primal_slice()))
+            .then_with(|| self.kicker_slice().cmp(other.kicker_slice()))
+    }
+}
+
+impl PartialOrd for Play {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Guard<Play> {
+    /// Compares plays by a total order, which is **not** the order of which play
+    /// beats which.
+    /// 
+    /// Plays are ordered by kind in the order of [`PlayKind`], then by the number
+    /// of primal ranks, i.e. by chain length, then by primal ranks and finally by
+    /// kicker ranks. Unlike comparison by strength with [`PartialOrd`], any two
+    /// plays are comparable, and plays differing only in their kickers are not
+    /// equal. Unlike the [`Ord`] implementation of [`Play`], shorter chains come
+    /// before longer ones.
+    /// 
+    /// See [`ByTotalOrder`] for using this order in ordered collections.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use dou_dizhu::*;
+    /// 
+    /// let short = play!(const { Eight, Nine, Ten, Jack, Queen }).unwrap();
+    /// let long = play!(const { Three, Four, Five, Six, Seven, Eight }).unwrap();
+    /// assert_eq!(short.total_cmp(&long), Ordering::Less);
+    /// assert_eq!(short.partial_cmp(&long), None);
+    /// 
+    /// let with_five = play!(const { King: 3, Five }).unwrap();
+    /// let with_six = play!(const { King: 3, Six }).unwrap();
+    /// assert_eq!(with_five.total_cmp(&with_six), Ordering::Less);
+    /// assert_eq!(with_five, with_six);
+    /// 
+    /// // Bombs beat pairs, yet come after them.
+    /// let bomb = play!(const { Three: 4 }).unwrap();
+    /// let pair = play!(const { Two: 2 }).unwrap();
+    /// assert_eq!(bomb.total_cmp(&pair), Ordering::Greater);
+    /// assert_eq!(play!(const { Two }).unwrap().total_cmp(&bomb), Ordering::Less);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.kind()
+            .cmp(&other.kind())
+            .then_with(|| self.0.primal_slice().len().cmp(&other.0.primal_slice().len()))
+            .then_with(|| self.0.primal_slice().cmp(other.0.primal_slice()))
+            .then_with(|| self.0.kicker_slice().cmp(other.0.kicker_slice()))
+    }
 }
 
-impl PartialEq for Guard<Play> {
+/// Wrapper ordering a play by [`Guard::<Play>::total_cmp`], for keeping plays in
+/// ordered collections such as [`BTreeSet`](alloc::collections::BTreeSet).
+/// 
+/// Two wrapped plays are equal only if they are the same play.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::collections::BTreeSet;
+/// use dou_dizhu::*;
+/// 
+/// let plays = Hand::FULL_DECK
+///     .plays(Pair)
+///     .chain(Hand::FULL_DECK.plays(Solo))
+///     .chain(Hand::FULL_DECK.plays(Solo))
+///     .map(ByTotalOrder)
+///     .collect::<BTreeSet<_>>();
+/// assert_eq!(plays.len(), 15 + 13);
+/// 
+/// let expected = Rank::iter()
+///     .map(Play::Solo)
+///     .chain(Rank::iter().take(13).map(Play::Pair))
+///     .collect::<Vec<_>>();
+/// assert!(plays.into_iter().map(|x| x.0.into_inner()).eq(expected));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByTotalOrder(pub Guard<Play>);
+
+impl PartialEq for ByTotalOrder {
     fn eq(&self, other: &Self) -> bool {
-        self.partial_cmp(other).is_some_and(|x| x.is_eq())
+        self.cmp(other).is_eq()
     }
 }
 
-impl PartialOrd for Guard<Play> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if mem::discriminant(&self.0) != mem::discriminant(&other.0) {
-            let self_level = match self.0 {
-                Play::Bomb(_) => 1,
-                Play::Rocket => 2,
-                _ => 0,
-            };
-            let other_level = match other.0 {
-                Play::Bomb(_) => 1,
-                Play::Rocket => 2,
-                _ => 0,
-            };
-            if self_level == other_level { // they are implicitly both zero
-                return None;
-            }
-            return self_level.partial_cmp(&other_level);
+impl Eq for ByTotalOrder {}
+
+impl PartialOrd for ByTotalOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByTotalOrder {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Play {
+    /// Returns the primal ranks of this play in ascending order.
+    fn primal_slice(&self) -> &[Rank] {
+        match self {
+            Play::Solo(rank)
+            | Play::Pair(rank)
+            | Play::Trio(rank)
+            | Play::Bomb(rank)
+            | Play::TrioWithSolo { trio: rank, .. }
+            | Play::TrioWithPair { trio: rank, .. }
+            | Play::FourWithDualSolo { four: rank, .. }
+            | Play::FourWithDualPair { four: rank, .. } => slice::from_ref(rank),
+            Play::Chain(ranks)
+            | Play::PairsChain(ranks)
+            | Play::Airplane(ranks)
+            | Play::AirplaneWithSolos { airplane: ranks, .. }
+            | Play::AirplaneWithPairs { airplane: ranks, .. } => ranks,
+            Play::Rocket => &[Rank::BlackJoker, Rank::RedJoker],
         }
-        macro_rules! generate_match {
-            (
-                match ($self_: ident, $other: ident) {
-                    $($t:tt)*
-                }
-            ) => {
-                generate_match_helper!(($self_, $other)($($t)*) -> ())
-            };
+    }
+
+    /// Returns the kicker ranks of this play in ascending order.
+    fn kicker_slice(&self) -> &[Rank] {
+        match self {
+            Play::TrioWithSolo { solo: rank, .. }
+            | Play::TrioWithPair { pair: rank, .. } => slice::from_ref(rank),
+            Play::AirplaneWithSolos { solos: ranks, .. }
+            | Play::AirplaneWithPairs { pairs: ranks, .. } => ranks,
+            Play::FourWithDualSolo { dual_solo: ranks, .. }
+            | Play::FourWithDualPair { dual_pair: ranks, .. } => ranks,
+            _ => &[],
         }
-        macro_rules! generate_match_helper {
-            (($self_: ident, $other: ident)() -> ($($body:tt)*)) => {
-                match $self_.0 {
-                    $($body)*
-                    Play::Rocket => Some(Ordering::Equal),
-                }
-            };
-            (($self_: ident, $other: ident)($variant:ident { $field:tt: _, .. } => _, $($t:tt)*) -> ($($body:tt)*)) => {
-                generate_match_helper!(($self_, $other)($($t)*) -> (
-                    $($body)*
-                    Play::$variant { $field: self_rank, .. } => {
-                        let Play::$variant { $field: other_rank, .. } = $other.0 else { unreachable!() };
-                        self_rank.partial_cmp(&other_rank)
-                    }
-                ))
-            };
-            (($self_: ident, $other: ident)($variant:ident { $field:tt: ref _, .. } => _, $($t:tt)*) -> ($($body:tt)*)) => {
-                generate_match_helper!(($self_, $other)($($t)*) -> (
-                    $($body)*
-                    Play::$variant { $field: ref self_ranks, .. } => {
-                        let Play::$variant { $field: ref other_ranks, .. } = $other.0 else { unreachable!() };
-                        if self_ranks.len() == other_ranks.len() {
-                            self_ranks[0].partial_cmp(&other_ranks[0])
-                        } else {
-                            None
-                        }
-                    }
-                ))
-            };
+    }
+
+    /// Compares two plays by the rules of the game, assuming both are valid.
+    fn cmp_by_rules(&self, other: &Play) -> Option<Ordering> {
+        if mem::discriminant(self) != mem::discriminant(other) {
+            return self.kind().beats_cmp(&other.kind());
         }
-        generate_match!(
-            match (self, other) {
-                Solo { 0: _, .. } => _,
-                Chain { 0: ref _, .. } => _,
-                Pair { 0: _, .. } => _,
-                PairsChain { 0: ref _, .. } => _,
-                Trio { 0: _, .. } => _,
-                Airplane { 0: ref _, .. } => _,
-                TrioWithSolo { trio: _, .. } => _,
-                AirplaneWithSolos { airplane: ref _, .. } => _,
-                TrioWithPair { trio: _, .. } => _,
-                AirplaneWithPairs { airplane: ref _, .. } => _,
-                Bomb { 0: _, .. } => _,
-                FourWithDualSolo { four: _, .. } => _,
-                FourWithDualPair { four: _, .. } => _,
-            }
-        )
+        let (self_primal, other_primal) = (self.primal_slice(), other.primal_slice());
+        if self_primal.len() == other_primal.len() {
+            self_primal.partial_cmp(other_primal)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks that a deck holds `count` cards of `rank`.
+const fn check_count(rank: Rank, count: u8) -> Result<(), PlayError> {
+    if count > rank.max_count() {
+        Err(PlayError::TooManyOfRank { rank, count })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the `len` consecutive ranks of a chain starting at `start`, which must
+/// be at least `min` long.
+fn chain_ranks(start: Rank, len: usize, min: usize) -> Result<Vec<Rank>, PlayError> {
+    if len < min {
+        return Err(PlayError::ChainTooShort { len, min });
+    }
+    if start > Rank::Ace {
+        return Err(PlayError::RankNotChainEligible { rank: start });
     }
+    match (start as usize).checked_add(len) {
+        Some(end) if end <= Rank::Ace as usize + 1 => {}
+        _ => return Err(PlayError::ChainExceedsAce { start, len }),
+    }
+    Ok(Rank::iter().skip(start as usize).take(len).collect())
 }
 
+/// Number of cards in the largest standard play, an airplane of twelve trios.
+const MAX_PLAY_SIZE: usize = 36;
+
+/// All play kinds in declaration order.
+const ALL_KINDS: [PlayKind; 14] = [
+    PlayKind::Solo,
+    PlayKind::Chain,
+    PlayKind::Pair,
+    PlayKind::PairsChain,
+    PlayKind::Trio,
+    PlayKind::Airplane,
+    PlayKind::TrioWithSolo,
+    PlayKind::AirplaneWithSolos,
+    PlayKind::TrioWithPair,
+    PlayKind::AirplaneWithPairs,
+    PlayKind::Bomb,
+    PlayKind::FourWithDualSolo,
+    PlayKind::FourWithDualPair,
+    PlayKind::Rocket,
+];
+
+/// All play kinds other than [`PlayKind::Bomb`] and [`PlayKind::Rocket`], in
+/// declaration order.
+const NON_BOMB_KINDS: [PlayKind; 12] = [
+    PlayKind::Solo,
+    PlayKind::Chain,
+    PlayKind::Pair,
+    PlayKind::PairsChain,
+    PlayKind::Trio,
+    PlayKind::Airplane,
+    PlayKind::TrioWithSolo,
+    PlayKind::AirplaneWithSolos,
+    PlayKind::TrioWithPair,
+    PlayKind::AirplaneWithPairs,
+    PlayKind::FourWithDualSolo,
+    PlayKind::FourWithDualPair,
+];
+
 /// Category of a standard Dou Dizhu play.
 /// 
 /// For the full specification of standard plays, see the
 /// [Pagat rules for Dou Dizhu](https://www.pagat.com/climbing/doudizhu.html).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// 
+/// Kinds are ordered by their [presentation order](PlayKind::display_order).
+/// Use [`beats_cmp`](PlayKind::beats_cmp) to compare kinds by the rules of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PlayKind {
     /// Any single card.
     Solo,
@@ -265,24 +1688,451 @@ pub enum PlayKind {
     Rocket,
 }
 
-impl PartialOrd for PlayKind {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl PlayKind {
+    /// Returns all play kinds in declaration order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(PlayKind::all().len(), 14);
+    /// assert_eq!(PlayKind::all()[0], Solo);
+    /// assert!(PlayKind::all().is_sorted());
+    /// ```
+    pub const fn all() -> &'static [PlayKind; 14] {
+        &ALL_KINDS
+    }
+
+    /// Returns the play kinds that can only be beaten by a play of the same kind,
+    /// a bomb or the rocket, i.e. all but [`Bomb`](PlayKind::Bomb) and
+    /// [`Rocket`](PlayKind::Rocket), in declaration order.
+    /// 
+    /// Note that four with dual solos or pairs is not a bomb.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(PlayKind::all_non_bomb().len(), 12);
+    /// assert!(PlayKind::all_non_bomb().contains(&FourWithDualSolo));
+    /// assert!(!PlayKind::all_non_bomb().contains(&Bomb));
+    /// ```
+    pub const fn all_non_bomb() -> &'static [PlayKind] {
+        &NON_BOMB_KINDS
+    }
+
+    /// Returns the position of this kind in presentation order, which is the
+    /// declaration order of `PlayKind`.
+    /// 
+    /// This is the order used by the `Ord` implementation of `PlayKind`. It is meant
+    /// for sorting and displaying plays and says nothing about which kind beats which;
+    /// see [`beats_cmp`](PlayKind::beats_cmp) for that.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Solo.display_order(), 0);
+    /// assert_eq!(Rocket.display_order(), 13);
+    /// assert!(Chain < Pair);
+    /// ```
+    pub const fn display_order(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns `true` if plays of this kind are built on a run of consecutive ranks,
+    /// namely chains, pairs chains and airplanes, with or without kickers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(AirplaneWithPairs.is_chain_type());
+    /// assert!(!TrioWithPair.is_chain_type());
+    /// ```
+    pub const fn is_chain_type(self) -> bool {
+        matches!(
+            self,
+            PlayKind::Chain
+                | PlayKind::PairsChain
+                | PlayKind::Airplane
+                | PlayKind::AirplaneWithSolos
+                | PlayKind::AirplaneWithPairs
+        )
+    }
+
+    /// Returns `true` if plays of this kind carry kickers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(TrioWithSolo.has_kicker());
+    /// assert!(FourWithDualPair.has_kicker());
+    /// assert!(!Airplane.has_kicker());
+    /// assert!(!Rocket.has_kicker());
+    /// ```
+    pub const fn has_kicker(self) -> bool {
+        self.kicker_size() != 0
+    }
+
+    /// Returns the number of cards of each primal rank in plays of this kind, as in
+    /// [`PlaySpec::primal_size`].
+    /// 
+    /// The rocket counts as two primal ranks of one card each.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Chain.primal_size(), 1);
+    /// assert_eq!(AirplaneWithPairs.primal_size(), 3);
+    /// assert_eq!(FourWithDualSolo.primal_size(), 4);
+    /// assert_eq!(Rocket.primal_size(), 1);
+    /// ```
+    pub const fn primal_size(self) -> u8 {
+        match self {
+            PlayKind::Rocket => 1,
+            kind => PlaySpec::standard(kind).primal_size,
+        }
+    }
+
+    /// Returns the number of cards of each kicker rank in plays of this kind, as in
+    /// [`PlaySpec::kicker_size`], or `0` if they carry no kickers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Trio.kicker_size(), 0);
+    /// assert_eq!(AirplaneWithSolos.kicker_size(), 1);
+    /// assert_eq!(FourWithDualPair.kicker_size(), 2);
+    /// ```
+    pub const fn kicker_size(self) -> u8 {
+        match self {
+            PlayKind::Rocket => 0,
+            kind => PlaySpec::standard(kind).kicker_size,
+        }
+    }
+
+    /// Returns the smallest number of primal ranks in a play of this kind, i.e. the
+    /// minimum chain length for chain-like kinds and `1` for most others.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Chain.min_primal_count(), 5);
+    /// assert_eq!(PairsChain.min_primal_count(), 3);
+    /// assert_eq!(AirplaneWithSolos.min_primal_count(), 2);
+    /// assert_eq!(Bomb.min_primal_count(), 1);
+    /// assert_eq!(Rocket.min_primal_count(), 2);
+    /// ```
+    pub const fn min_primal_count(self) -> u8 {
+        match self {
+            PlayKind::Rocket => 2,
+            kind => *PlaySpec::standard(kind).primal_count.start(),
+        }
+    }
+
+    /// Returns the largest number of primal ranks in a play of this kind, or `None`
+    /// for the rocket, which [`PlaySpec`] cannot express.
+    /// 
+    /// Chains are bounded by the ranks from Three to Ace, and chain-like kinds with
+    /// kickers additionally by the distinct ranks left for the kickers: seven trios
+    /// leave eight ranks for the solos, of which the two jokers cannot be played
+    /// together, and six trios leave seven ranks for the pairs, the jokers having
+    /// no pair.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Chain.max_primal_count(), Some(12));
+    /// assert_eq!(AirplaneWithSolos.max_primal_count(), Some(7));
+    /// assert_eq!(AirplaneWithPairs.max_primal_count(), Some(6));
+    /// assert_eq!(Solo.max_primal_count(), Some(1));
+    /// assert_eq!(Rocket.max_primal_count(), None);
+    /// 
+    /// // The full deck holds a play of every kind with the largest number of primal ranks.
+    /// for &kind in PlayKind::all().iter().filter(|&&kind| kind != Rocket) {
+    ///     let max = Hand::FULL_DECK.plays(kind).map(|x| x.chain_length().unwrap_or(1) as u8).max();
+    ///     assert_eq!(kind.max_primal_count(), max, "{kind:?}");
+    /// }
+    /// ```
+    pub const fn max_primal_count(self) -> Option<u8> {
+        match self {
+            PlayKind::Rocket => None,
+            kind => Some(*PlaySpec::standard(kind).primal_count.end()),
+        }
+    }
+
+    /// Returns `true` if a play of this kind can consist of exactly `n` cards.
+    /// 
+    /// The sizes follow from the chain lengths allowed by [`PlaySpec::standard`](crate::core::PlaySpec::standard).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(PairsChain.has_size(8));
+    /// assert!(!PairsChain.has_size(9));
+    /// ```
+    pub const fn has_size(self, n: usize) -> bool {
+        match self {
+            PlayKind::Solo => n == 1,
+            PlayKind::Chain => 5 <= n && n <= 12,
+            PlayKind::Pair => n == 2,
+            PlayKind::PairsChain => n.is_multiple_of(2) && 6 <= n && n <= 24,
+            PlayKind::Trio => n == 3,
+            PlayKind::Airplane => n.is_multiple_of(3) && 6 <= n && n <= 36,
+            PlayKind::TrioWithSolo => n == 4,
+            PlayKind::AirplaneWithSolos => n.is_multiple_of(4) && 8 <= n && n <= 28,
+            PlayKind::TrioWithPair => n == 5,
+            PlayKind::AirplaneWithPairs => n.is_multiple_of(5) && 10 <= n && n <= 30,
+            PlayKind::Bomb => n == 4,
+            PlayKind::FourWithDualSolo => n == 6,
+            PlayKind::FourWithDualPair => n == 8,
+            PlayKind::Rocket => n == 2,
+        }
+    }
+
+    /// Returns an iterator over the possible numbers of cards in a play of this kind,
+    /// in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(Airplane.possible_sizes().eq((6..=36).step_by(3)));
+    /// assert_eq!(Chain.possible_sizes().min(), Some(5));
+    /// assert_eq!(Chain.possible_sizes().max(), Some(12));
+    /// 
+    /// // Six trios and six pairs already take 12 of the 13 ranks below the jokers.
+    /// assert_eq!(AirplaneWithPairs.possible_sizes().max(), Some(30));
+    /// 
+    /// // Every size is reached by some play in the full deck, and no other is.
+    /// for &kind in PlayKind::all() {
+    ///     let mut sizes = Hand::FULL_DECK.plays(kind).map(|x| x.card_count()).collect::<Vec<_>>();
+    ///     sizes.sort();
+    ///     sizes.dedup();
+    ///     assert!(kind.possible_sizes().eq(sizes), "{kind:?}");
+    /// }
+    /// ```
+    pub fn possible_sizes(self) -> impl Iterator<Item = usize> {
+        (1..=MAX_PLAY_SIZE).filter(move |&n| self.has_size(n))
+    }
+
+    /// Returns the kinds of plays that can consist of exactly `n` cards,
+    /// in declaration order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(PlayKind::candidates_for_size(4), &[TrioWithSolo, Bomb]);
+    /// assert_eq!(PlayKind::candidates_for_size(6), &[Chain, PairsChain, Airplane, FourWithDualSolo]);
+    /// assert_eq!(PlayKind::candidates_for_size(7), &[Chain]);
+    /// assert!(PlayKind::candidates_for_size(0).is_empty());
+    /// assert!(PlayKind::candidates_for_size(37).is_empty());
+    /// ```
+    pub fn candidates_for_size(n: usize) -> &'static [PlayKind] {
+        const TABLE: ([[PlayKind; 14]; MAX_PLAY_SIZE + 1], [usize; MAX_PLAY_SIZE + 1]) = {
+            let mut kinds = [[PlayKind::Solo; 14]; MAX_PLAY_SIZE + 1];
+            let mut lens = [0; MAX_PLAY_SIZE + 1];
+            let mut n = 0;
+            while n <= MAX_PLAY_SIZE {
+                let mut i = 0;
+                while i < 14 {
+                    if ALL_KINDS[i].has_size(n) {
+                        kinds[n][lens[n]] = ALL_KINDS[i];
+                        lens[n] += 1;
+                    }
+                    i += 1;
+                }
+                n += 1;
+            }
+            (kinds, lens)
+        };
+        if n > MAX_PLAY_SIZE {
+            return &[];
+        }
+        &TABLE.0[n][..TABLE.1[n]]
+    }
+
+    /// Compares two kinds by the rules of the game.
+    /// 
+    /// Returns `Some(Ordering::Equal)` for equal kinds, whose plays are compared by rank.
+    /// A rocket beats a bomb, which in turn beats all other kinds. Two different kinds
+    /// that are neither bombs nor rockets cannot be played against each other, for
+    /// which `None` is returned.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Bomb.beats_cmp(&Solo), Some(Ordering::Greater));
+    /// assert_eq!(Bomb.beats_cmp(&Rocket), Some(Ordering::Less));
+    /// assert_eq!(Chain.beats_cmp(&Pair), None);
+    /// ```
+    pub fn beats_cmp(&self, other: &Self) -> Option<Ordering> {
         if self.eq(other) {
             return Some(Ordering::Equal);
         }
-        let self_level = match self {
-            Self::Bomb => 1,
-            Self::Rocket => 2,
-            _ => 0,
-        };
-        let other_level = match other {
-            Self::Bomb => 1,
-            Self::Rocket => 2,
-            _ => 0,
-        };
-        match self_level.cmp(&other_level) {
+        match self.level().cmp(&other.level()) {
             Ordering::Equal => None,
             ord => Some(ord),
         }
     }
+
+    /// Returns `true` if some play of kind `other` beats some play of this kind.
+    /// 
+    /// This holds for plays of the same kind, except for the unique rocket, and
+    /// whenever [`beats_cmp`](PlayKind::beats_cmp) ranks `other` higher.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(Pair.can_be_beaten_by(Pair));
+    /// assert!(Pair.can_be_beaten_by(Bomb));
+    /// assert!(Bomb.can_be_beaten_by(Rocket));
+    /// assert!(!Pair.can_be_beaten_by(Solo));
+    /// assert!(!Bomb.can_be_beaten_by(FourWithDualSolo));
+    /// assert!(!Rocket.can_be_beaten_by(Rocket));
+    /// ```
+    pub const fn can_be_beaten_by(self, other: PlayKind) -> bool {
+        match (self, other) {
+            (PlayKind::Rocket, _) => false,
+            _ if self as u8 == other as u8 => true,
+            _ => other.level() > self.level(),
+        }
+    }
+
+    /// Returns `true` if some play of this kind beats some play of kind `other`.
+    /// 
+    /// This is [`can_be_beaten_by`](PlayKind::can_be_beaten_by) in the other direction.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert!(Bomb.beats_any_of_kind(AirplaneWithPairs));
+    /// assert!(Rocket.beats_any_of_kind(Bomb));
+    /// assert!(!Chain.beats_any_of_kind(Solo));
+    /// ```
+    pub const fn beats_any_of_kind(self, other: PlayKind) -> bool {
+        other.can_be_beaten_by(self)
+    }
+
+    /// Returns `true` for [`Bomb`](PlayKind::Bomb) and [`Rocket`](PlayKind::Rocket),
+    /// the kinds of the plays that beat every play of the other kinds.
+    /// 
+    /// Bomb-like plays double the stake of a deal, see
+    /// [`Play::score_multiplier`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let bomb_like = PlayKind::all().iter().filter(|x| x.is_bomb_like()).collect::<Vec<_>>();
+    /// assert_eq!(bomb_like, [&Bomb, &Rocket]);
+    /// ```
+    pub const fn is_bomb_like(self) -> bool {
+        self.level() > 0
+    }
+
+    /// Returns the level of this kind in the rules of the game: plays of a higher
+    /// level beat all plays of a lower level.
+    const fn level(self) -> u8 {
+        match self {
+            PlayKind::Bomb => 1,
+            PlayKind::Rocket => 2,
+            _ => 0,
+        }
+    }
+}
+
+impl PartialOrd for PlayKind {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders kinds by [`display_order`](PlayKind::display_order).
+impl Ord for PlayKind {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.display_order().cmp(&other.display_order())
+    }
+}
+
+/// Error returned when constructing an invalid [`Play`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayError {
+    /// The play would use more cards of `rank` than a complete deck holds.
+    TooManyOfRank { rank: Rank, count: u8 },
+    /// A kicker would have the rank `rank` of primal cards.
+    KickerOverlapsPrimal { rank: Rank },
+    /// The ranks of a chain are not consecutive in ascending order.
+    NotConsecutive,
+    /// The play has `got` kickers where its kind requires `expected`.
+    KickerCountMismatch { expected: usize, got: usize },
+    /// The kicker `rank` appears more than once.
+    DuplicateKicker { rank: Rank },
+    /// The kickers are not in ascending order.
+    KickersNotAscending,
+    /// Both jokers would be used as solo kickers.
+    RocketAsKickers,
+    /// The chain would be `len` long, shorter than the minimum of `min` for its kind.
+    ChainTooShort { len: usize, min: usize },
+    /// The chain would contain `rank`, which cannot appear in chains.
+    RankNotChainEligible { rank: Rank },
+    /// The chain of `len` ranks from `start` would go past `Ace`.
+    ChainExceedsAce { start: Rank, len: usize },
+}
+
+impl fmt::Display for PlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyOfRank { rank, count } => {
+                write!(f, "a deck has fewer than {} `{:?}`s", count, rank)
+            }
+            Self::KickerOverlapsPrimal { rank } => {
+                write!(f, "kicker `{:?}` has the rank of primal cards", rank)
+            }
+            Self::NotConsecutive => write!(f, "chain ranks are not consecutive"),
+            Self::KickerCountMismatch { expected, got } => {
+                write!(f, "expected {} kickers, got {}", expected, got)
+            }
+            Self::DuplicateKicker { rank } => write!(f, "kicker `{:?}` appears more than once", rank),
+            Self::KickersNotAscending => write!(f, "kickers are not in ascending order"),
+            Self::RocketAsKickers => write!(f, "both jokers cannot be used as solo kickers"),
+            Self::ChainTooShort { len, min } => {
+                write!(f, "chain of length {} is shorter than the minimum of {}", len, min)
+            }
+            Self::RankNotChainEligible { rank } => write!(f, "`{:?}` cannot appear in a chain", rank),
+            Self::ChainExceedsAce { start, len } => {
+                write!(f, "chain of length {} starting at `{:?}` goes past `Ace`", len, start)
+            }
+        }
+    }
 }
+
+impl Error for PlayError {}
@@ -1,7 +1,20 @@
+use core::mem;
+
 /// A card rank in Dou Dizhu.
+/// 
+/// The conventional default is the lowest rank, `Three`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::*;
+/// 
+/// assert_eq!(Rank::default(), Rank::Three);
+/// ```
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Rank {
+    #[default]
     Three,
     Four,
     Five,
@@ -18,3 +31,71 @@ pub enum Rank {
     BlackJoker,
     RedJoker,
 }
+
+impl Rank {
+    /// Returns an iterator over all ranks in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Rank::iter().count(), 15);
+    /// assert_eq!(Rank::iter().next(), Some(Rank::Three));
+    /// assert_eq!(Rank::iter().last(), Some(Rank::RedJoker));
+    /// assert_eq!(Rank::iter().rev().nth(2), Some(Rank::Two));
+    /// ```
+    pub fn iter() -> impl DoubleEndedIterator<Item = Rank> + ExactSizeIterator {
+        (0u8..15).map(|i| unsafe { mem::transmute::<u8, Rank>(i) })
+    }
+
+    /// Returns the symbol of this rank: `'3'` to `'9'`, `'T'` for Ten, `'J'`,
+    /// `'Q'`, `'K'`, `'A'`, `'2'`, and `'B'` and `'R'` for the jokers.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let symbols = Rank::iter().map(Rank::symbol).collect::<String>();
+    /// assert_eq!(symbols, "3456789TJQKA2BR");
+    /// ```
+    pub const fn symbol(self) -> char {
+        SYMBOLS[self as usize]
+    }
+
+    /// Returns the rank with the given [`symbol`](Rank::symbol), or `None` if
+    /// there is none. Lowercase letters are accepted too.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// assert_eq!(Rank::from_symbol('T'), Some(Rank::Ten));
+    /// assert_eq!(Rank::from_symbol('r'), Some(Rank::RedJoker));
+    /// assert_eq!(Rank::from_symbol('1'), None);
+    /// assert!(Rank::iter().all(|rank| Rank::from_symbol(rank.symbol()) == Some(rank)));
+    /// ```
+    pub const fn from_symbol(symbol: char) -> Option<Rank> {
+        let symbol = symbol.to_ascii_uppercase();
+        let mut i = 0;
+        while i < 15 {
+            if SYMBOLS[i] == symbol {
+                return Some(unsafe { mem::transmute::<u8, Rank>(i as u8) });
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the number of cards of this rank in a complete deck.
+    pub(crate) const fn max_count(self) -> u8 {
+        match self {
+            Rank::BlackJoker | Rank::RedJoker => 1,
+            _ => 4,
+        }
+    }
+}
+
+const SYMBOLS: [char; 15] = ['3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A', '2', 'B', 'R'];
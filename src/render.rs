@@ -0,0 +1,128 @@
+use alloc::string::String;
+use crate::{core::Guard, Hand, Play, Rank};
+
+/// Style of the text produced by [`Hand::render`] and [`Guard::<Play>::render`].
+/// 
+/// Cards are always listed from the highest rank to the lowest, and the output
+/// does not depend on the locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RenderStyle {
+    /// Every card as the [`symbol`](Rank::symbol) of its rank, separated by spaces,
+    /// e.g. `2 2 A K K Q`.
+    #[default]
+    Ascii,
+    /// Every rank as a full-width glyph followed by the number of its cards, e.g.
+    /// `２×2 Ａ×1 Ｋ×2 Ｑ×1`.
+    /// 
+    /// The Black Joker is 🃏 (U+1F0CF) and the Red Joker 🂿 (U+1F0BF). Terminals
+    /// usually draw the Red Joker narrower than the other glyphs, so groups are not
+    /// guaranteed to line up.
+    Unicode,
+}
+
+impl Hand {
+    /// Renders the cards of this hand as text in the given style.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let hand = hand!(const { Two: 2, Ace, King: 2, Queen, Ten, Three });
+    /// assert_eq!(hand.render(RenderStyle::Ascii), "2 2 A K K Q T 3");
+    /// assert_eq!(hand.render(RenderStyle::Unicode), "２×2 Ａ×1 Ｋ×2 Ｑ×1 Ｔ×1 ３×1");
+    /// 
+    /// let jokers = hand!(const { Four: 3, BlackJoker, RedJoker });
+    /// assert_eq!(jokers.render(RenderStyle::Ascii), "R B 4 4 4");
+    /// assert_eq!(jokers.render(RenderStyle::Unicode), "🂿×1 🃏×1 ４×3");
+    /// 
+    /// let mixed = hand!(const { Three, RedJoker });
+    /// assert_eq!(mixed.render(RenderStyle::Ascii), "R 3");
+    /// assert_eq!(mixed.render(RenderStyle::Unicode), "🂿×1 ３×1");
+    /// 
+    /// assert_eq!(Hand::EMPTY.render(RenderStyle::Ascii), "");
+    /// assert_eq!(Hand::EMPTY.render(RenderStyle::Unicode), "");
+    /// 
+    /// assert_eq!(
+    ///     Hand::FULL_DECK.render(RenderStyle::Ascii),
+    ///     "R B 2 2 2 2 A A A A K K K K Q Q Q Q J J J J T T T T 9 9 9 9 \
+    ///      8 8 8 8 7 7 7 7 6 6 6 6 5 5 5 5 4 4 4 4 3 3 3 3",
+    /// );
+    /// assert_eq!(
+    ///     Hand::FULL_DECK.render(RenderStyle::Unicode),
+    ///     "🂿×1 🃏×1 ２×4 Ａ×4 Ｋ×4 Ｑ×4 Ｊ×4 Ｔ×4 ９×4 ８×4 ７×4 ６×4 ５×4 ４×4 ３×4",
+    /// );
+    /// ```
+    pub fn render(&self, style: RenderStyle) -> String {
+        let mut text = String::new();
+        push_cards(&mut text, *self, style);
+        text
+    }
+}
+
+impl Guard<Play> {
+    /// Renders this play as text in the given style.
+    /// 
+    /// The primal cards come first, followed by ` + ` and the kicker cards if the
+    /// play has kickers, each part being rendered like [`Hand::render`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::*;
+    /// 
+    /// let play = play!(const { Nine: 3, Ten: 3, Ace, Four }).unwrap();
+    /// assert_eq!(play.render(RenderStyle::Ascii), "T T T 9 9 9 + A 4");
+    /// assert_eq!(play.render(RenderStyle::Unicode), "Ｔ×3 ９×3 + Ａ×1 ４×1");
+    /// 
+    /// let rocket = play!(const { BlackJoker, RedJoker }).unwrap();
+    /// assert_eq!(rocket.render(RenderStyle::Ascii), "R B");
+    /// assert_eq!(rocket.render(RenderStyle::Unicode), "🂿×1 🃏×1");
+    /// ```
+    pub fn render(&self, style: RenderStyle) -> String {
+        let mut text = String::new();
+        push_cards(&mut text, self.primal_hand(), style);
+        let kickers = self.kicker_hand();
+        if !kickers.is_empty() {
+            text.push_str(" + ");
+            push_cards(&mut text, kickers, style);
+        }
+        text
+    }
+}
+
+fn push_cards(text: &mut String, hand: Hand, style: RenderStyle) {
+    let mut first = true;
+    for rank in Rank::iter().rev().filter(|&rank| hand[rank] > 0) {
+        match style {
+            RenderStyle::Ascii => {
+                for _ in 0..hand[rank] {
+                    if !first {
+                        text.push(' ');
+                    }
+                    text.push(rank.symbol());
+                    first = false;
+                }
+            }
+            RenderStyle::Unicode => {
+                if !first {
+                    text.push(' ');
+                }
+                text.push(glyph(rank));
+                text.push('×');
+                text.push(char::from(b'0' + hand[rank]));
+                first = false;
+            }
+        }
+    }
+}
+
+/// Returns the full-width glyph of `rank`.
+fn glyph(rank: Rank) -> char {
+    match rank {
+        Rank::BlackJoker => '\u{1F0CF}',
+        Rank::RedJoker => '\u{1F0BF}',
+        // The full-width forms of ASCII characters are offset by a constant.
+        rank => char::from_u32(rank.symbol() as u32 + 0xFEE0).unwrap(),
+    }
+}
@@ -0,0 +1,178 @@
+//! Scoring of a finished deal.
+//! 
+//! The stake of a deal is the winning bid, doubled for every bomb and rocket
+//! played and doubled once more for a spring. Each peasant pays the stake to the
+//! landlord or receives it from the landlord, so the landlord wins or loses
+//! twice the stake.
+//! 
+//! Seats are indexed with the landlord at index 0 and the peasants at 1 and 2.
+
+use crate::{core::Guard, Play};
+
+/// A doubling for one side playing (almost) no cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spring {
+    /// The landlord won before either peasant played a card.
+    Spring,
+    /// The peasants won while the landlord played only the first lead.
+    AntiSpring,
+}
+
+impl Spring {
+    /// Detects a spring from the number of plays (not counting passes) made by
+    /// each seat, with the landlord at index 0.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::score::Spring;
+    /// 
+    /// assert_eq!(Spring::detect([6, 0, 0], true), Some(Spring::Spring));
+    /// assert_eq!(Spring::detect([1, 4, 2], false), Some(Spring::AntiSpring));
+    /// assert_eq!(Spring::detect([6, 1, 0], true), None);
+    /// assert_eq!(Spring::detect([2, 4, 2], false), None);
+    /// ```
+    pub const fn detect(play_counts: [u32; 3], landlord_won: bool) -> Option<Spring> {
+        match (landlord_won, play_counts) {
+            (true, [_, 0, 0]) => Some(Spring::Spring),
+            (false, [1, _, _]) => Some(Spring::AntiSpring),
+            _ => None,
+        }
+    }
+}
+
+/// Detects a spring from the log of a finished deal.
+/// 
+/// `plays` holds the moves of the deal in order, each with the seat making it
+/// and the play, or `None` for a pass. Unlike elsewhere in this module, the
+/// landlord sits at seat `landlord`. The winner is the seat making the last play,
+/// and the spring is detected from the number of plays of each seat as by
+/// [`Spring::detect`]. Returns `None` if no play was made.
+/// 
+/// # Panics
+/// 
+/// Panics if `landlord` or a seat in `plays` is not less than 3.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, score::{detect_spring, Spring}};
+/// 
+/// let solo = |rank| Hand::EMPTY.set_count(rank, 1).unwrap().to_play();
+/// let pair = |rank| Hand::EMPTY.set_count(rank, 2).unwrap().to_play();
+/// 
+/// // The peasants pass throughout.
+/// let log = [(2, pair(Rank::Four)), (0, None), (1, None), (2, pair(Rank::Two))];
+/// assert_eq!(detect_spring(&log, 2), Some(Spring::Spring));
+/// 
+/// // The landlord leads once and never plays again.
+/// let log = [
+///     (0, solo(Rank::Three)),
+///     (1, solo(Rank::Ace)),
+///     (2, None),
+///     (0, None),
+///     (1, pair(Rank::Six)),
+///     (2, None),
+///     (0, None),
+///     (1, pair(Rank::Nine)),
+/// ];
+/// assert_eq!(detect_spring(&log, 0), Some(Spring::AntiSpring));
+/// 
+/// // One peasant passes throughout, but the other plays.
+/// let log = [
+///     (0, solo(Rank::Three)),
+///     (1, None),
+///     (2, solo(Rank::King)),
+///     (0, solo(Rank::Two)),
+///     (1, None),
+///     (2, None),
+///     (0, pair(Rank::Five)),
+/// ];
+/// assert_eq!(detect_spring(&log, 0), None);
+/// 
+/// assert_eq!(detect_spring(&[], 0), None);
+/// ```
+pub fn detect_spring(plays: &[(usize, Option<Guard<Play>>)], landlord: usize) -> Option<Spring> {
+    assert!(landlord < 3, "seat index out of range");
+    let mut play_counts = [0u32; 3];
+    let mut winner = None;
+    for (seat, play) in plays {
+        assert!(*seat < 3, "seat index out of range");
+        if play.is_some() {
+            play_counts[(seat + 3 - landlord) % 3] += 1;
+            winner = Some(*seat);
+        }
+    }
+    Spring::detect(play_counts, winner? == landlord)
+}
+
+/// The factors determining the stake of a deal.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use dou_dizhu::{*, score::{self, ScoreSheet, Spring}};
+/// 
+/// let mut sheet = ScoreSheet::new(3);
+/// for play in [
+///     play!(const { Five: 4 }).unwrap(),
+///     play!(const { Six: 2 }).unwrap(),
+///     play!(const { BlackJoker, RedJoker }).unwrap(),
+/// ] {
+///     sheet.record(&play);
+/// }
+/// sheet.spring = Spring::detect([1, 5, 3], false);
+/// 
+/// assert_eq!(sheet.bombs_played, 2);
+/// assert_eq!(sheet.stake(), 24);
+/// assert_eq!(score::settle(&sheet, false), [-48, 24, 24]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScoreSheet {
+    /// The winning bid, usually 1 to 3.
+    pub bid: u8,
+    /// The number of bombs and rockets played.
+    pub bombs_played: u32,
+    /// The spring, if any.
+    pub spring: Option<Spring>,
+}
+
+impl ScoreSheet {
+    /// Creates a score sheet for a deal won with `bid`, before any play.
+    pub const fn new(bid: u8) -> Self {
+        Self { bid, bombs_played: 0, spring: None }
+    }
+
+    /// Records a play, doubling the stake if it is a bomb or the rocket.
+    pub fn record(&mut self, play: &Guard<Play>) {
+        if play.is_bomb_like() {
+            self.bombs_played += 1;
+        }
+    }
+
+    /// Returns the number of points each peasant pays or receives.
+    /// 
+    /// Saturates at [`i32::MAX`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use dou_dizhu::score::{ScoreSheet, Spring};
+    /// 
+    /// let sheet = ScoreSheet { bid: 2, bombs_played: 1, spring: Some(Spring::Spring) };
+    /// assert_eq!(sheet.stake(), 8);
+    /// assert_eq!(ScoreSheet::new(1).stake(), 1);
+    /// ```
+    pub const fn stake(&self) -> i32 {
+        let doublings = self.bombs_played.saturating_add(self.spring.is_some() as u32);
+        2i32.saturating_pow(doublings).saturating_mul(self.bid as i32)
+    }
+}
+
+/// Returns the point change of each seat, with the landlord at index 0.
+/// 
+/// See [`ScoreSheet`] for an example.
+pub const fn settle(sheet: &ScoreSheet, landlord_won: bool) -> [i32; 3] {
+    let stake = if landlord_won { sheet.stake() } else { -sheet.stake() };
+    [stake.saturating_mul(2), -stake, -stake]
+}
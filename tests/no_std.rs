@@ -0,0 +1,43 @@
+//! Checks that the crate is usable from a `no_std` crate with `alloc`.
+//! 
+//! Run with `cargo test --no-default-features` to build the library without std.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use dou_dizhu::*;
+
+#[test]
+fn plays_without_std() {
+    let hand = hand!(const { Three: 3, Four, Five: 2 });
+    let play = hand.to_play();
+    assert!(play.is_none());
+
+    let plays = hand.all_plays().collect::<Vec<_>>();
+    assert!(plays.iter().any(|x| x.kind() == TrioWithSolo));
+    assert_eq!(hand.min_play_count(), 2);
+
+    let trio = play!(const { Three: 3, Four }).unwrap();
+    assert_eq!(hand - &trio, Some(hand!(const { Five: 2 })));
+}
+
+#[test]
+fn composition_and_comparison_without_std() {
+    use dou_dizhu::core::{CompositionExt, PlaySpec, SearchExt};
+
+    let hand = hand!(const { Six: 3, Seven: 3, Three, Four });
+    let comp = hand.composition();
+    assert_eq!(comp.trios.ranks, [Rank::Six, Rank::Seven]);
+    assert!(comp.trios.consecutive);
+
+    let airplane = comp.guess_play().unwrap();
+    let higher = play!(const { Seven: 3, Eight: 3, Three, Five }).unwrap();
+    assert!(higher > airplane);
+    assert_eq!(higher.partial_cmp(&play!(const { Three: 3, Four }).unwrap()), None);
+
+    let count = SearchExt::plays(Hand::FULL_DECK, PlaySpec::standard(AirplaneWithSolos)).count();
+    assert_eq!(count, 7516);
+    assert_eq!(hand + &higher, None);
+}